@@ -0,0 +1,161 @@
+use std::{
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering::Relaxed},
+};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Installs [`Counting`] as the global allocator when this crate is built
+/// with the `count-alloc` feature, for bench binaries that have no allocator
+/// of their own.
+#[cfg(feature = "count-alloc")]
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[global_allocator]
+        static GLOBAL: $crate::alloc::Counting = $crate::alloc::Counting;
+    };
+}
+
+#[cfg(not(feature = "count-alloc"))]
+#[macro_export]
+macro_rules! count_allocations {
+    () => {};
+}
+
+/// MiMalloc, counting every allocation for [`measure`].
+#[cfg(feature = "count-alloc")]
+pub struct Counting;
+
+#[cfg(feature = "count-alloc")]
+unsafe impl std::alloc::GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = unsafe { mimalloc::MiMalloc.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size(), layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = unsafe { mimalloc::MiMalloc.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size(), layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        unsafe { mimalloc::MiMalloc.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    /// Counts as one allocation of the bytes it grew by.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { mimalloc::MiMalloc.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            allocated(new_size.saturating_sub(layout.size()), new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+fn allocated(bytes: usize, live: usize) {
+    if !INSTALLED.load(Relaxed) {
+        INSTALLED.store(true, Relaxed);
+    }
+    COUNT.fetch_add(1, Relaxed);
+    BYTES.fetch_add(bytes as u64, Relaxed);
+    let live = LIVE.fetch_add(live, Relaxed) + live;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+/// Whether [`Counting`] is the global allocator. It sets a flag on every
+/// allocation, so this allocates once to be sure there was one.
+pub fn installed() -> bool {
+    drop(std::hint::black_box(Box::new(0u8)));
+    INSTALLED.load(Relaxed)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live before.
+    pub peak: usize,
+}
+
+/// Runs `f`, counting the allocations of every thread meanwhile. All zero
+/// unless [`Counting`] is installed.
+///
+/// The counters are global, so the figures are only those of `f` when
+/// nothing else runs meanwhile. Concurrent calls, like the solvers of
+/// `aoc --repeat --concurrent`, count each other's allocations and reset
+/// each other's peak.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let count = COUNT.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+
+    let result = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Relaxed) - count,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (result, allocations)
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} bytes, peak {} bytes",
+            self.count, self.bytes, self.peak
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{installed, measure};
+
+    crate::count_allocations!();
+
+    #[test]
+    #[cfg(feature = "count-alloc")]
+    fn measure_counts_allocations_and_the_peak() {
+        let (len, allocations) = measure(|| {
+            let mut v: Vec<u8> = Vec::with_capacity(1000);
+            v.extend([1; 1000]);
+            v.reserve_exact(2000);
+            let small: Vec<u64> = (0..10).collect();
+            drop(v);
+            small.len()
+        });
+
+        // other tests may allocate meanwhile
+        assert!(installed());
+        assert_eq!(len, 10);
+        assert!(allocations.count >= 3, "{allocations}");
+        assert!(allocations.bytes >= 3000 + 80, "{allocations}");
+        assert!(allocations.peak >= 3000 + 80, "{allocations}");
+    }
+
+    #[test]
+    #[cfg(not(feature = "count-alloc"))]
+    fn measure_is_zero_without_the_allocator() {
+        let (_, allocations) = measure(|| vec![1u8; 1000]);
+
+        assert!(!installed());
+        assert_eq!(allocations, super::Allocations::default());
+    }
+}
@@ -0,0 +1,55 @@
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput};
+
+use crate::{Solution, alloc};
+
+/// Registers `parse`, `solve` and `total` benchmarks for `S` in a group named
+/// after the crate, so results of different days don't overwrite each other.
+/// Prints the allocations of one run first when they are counted.
+pub fn bench_solution<S: Solution>(c: &mut Criterion, name: &str, input: &str) {
+    if alloc::installed() {
+        let (_, allocations) = alloc::measure(|| S::run(input));
+        println!("{name}: {allocations}");
+    }
+
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+    group.bench_function("solve", |b| {
+        b.iter_batched(
+            || S::parse(input).unwrap(),
+            |parsed| S::solve(black_box(parsed)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("total", |b| b.iter(|| S::run(black_box(input))));
+
+    group.finish();
+}
+
+/// Benchmarks `solve` on a generated input for every size in a
+/// `<name>/scaling` group, so criterion can plot how the solution scales.
+pub fn bench_scaling<S: Solution>(
+    c: &mut Criterion,
+    name: &str,
+    sizes: &[usize],
+    generate: impl Fn(usize) -> String,
+) {
+    let mut group = c.benchmark_group(format!("{name}/scaling"));
+
+    for &size in sizes {
+        let input = generate(size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("solve", size), &input, |b, input| {
+            b.iter_batched(
+                || S::parse(input).unwrap(),
+                |parsed| S::solve(black_box(parsed)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
@@ -0,0 +1,42 @@
+use proptest::{collection::vec, prelude::*, sample::select};
+
+/// Widths where the SIMD loops change shape: 14 and 30 usable cells per
+/// SSE2/AVX2 load on day 4, 32 and 128 byte chunks on day 7.
+const BOUNDARIES: [usize; 6] = [14, 16, 30, 32, 64, 128];
+
+/// Grid widths clustered around the SIMD lane boundaries, where remainder
+/// masks tend to be off by one, mixed with arbitrary widths.
+pub fn width() -> impl Strategy<Value = usize> {
+    prop_oneof![
+        3 => select(&BOUNDARIES[..]).prop_flat_map(|b| b - 3..=b + 3),
+        1 => 1..=300usize,
+    ]
+}
+
+/// Rows of a grid of [`width`] columns, every cell picked from `cells`.
+pub fn grid(
+    height: std::ops::RangeInclusive<usize>,
+    cells: &'static [u8],
+) -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (width(), height).prop_flat_map(move |(width, height)| vec(vec(select(cells), width), height))
+}
+
+/// Joins the rows into newline terminated puzzle input.
+pub fn to_input(rows: &[Vec<u8>]) -> String {
+    let mut input = String::with_capacity(rows.iter().map(|row| row.len() + 1).sum());
+    for row in rows {
+        input.push_str(std::str::from_utf8(row).expect("grid cells are ASCII"));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_input;
+
+    #[test]
+    fn to_input_terminates_every_row() {
+        assert_eq!(to_input(&[b"..@".to_vec(), b"@..".to_vec()]), "..@\n@..\n");
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+/// Where and why a puzzle input could not be parsed.
+///
+/// Lines and columns are 1-based and counted in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub bytes: Vec<u8>,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Points at `offending`, which must be a subslice of `input`.
+    pub fn at(day: u8, input: &[u8], offending: &[u8], reason: impl Into<String>) -> Self {
+        let (line, column) = position(input, offending);
+
+        Self {
+            day,
+            line,
+            column,
+            bytes: offending.to_vec(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Points just past the last byte of `input`, for input that stops too early.
+    pub fn eof(day: u8, input: &[u8], reason: impl Into<String>) -> Self {
+        Self::at(day, input, &input[input.len()..], reason)
+    }
+
+    /// Rebases an error raised while parsing `part` on its own onto `input`,
+    /// of which `part` must be a subslice.
+    pub fn within(mut self, input: &[u8], part: &[u8]) -> Self {
+        let (line, column) = position(input, part);
+
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+
+        self
+    }
+}
+
+fn position(input: &[u8], part: &[u8]) -> (usize, usize) {
+    let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    assert!(
+        offset <= input.len() && offset + part.len() <= input.len(),
+        "slice does not point into the input"
+    );
+
+    let before = &input[..offset];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+
+    (line, offset - line_start + 1)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+
+        if self.bytes.is_empty() {
+            write!(f, " (at end of input)")
+        } else {
+            write!(f, " (found \"{}\")", self.bytes.escape_ascii())
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::ParseError;
+
+    const INPUT: &[u8] = b"L68\nL30\nX48\n";
+
+    #[rstest]
+    #[case(0, 3, 1, 1)]
+    #[case(5, 2, 2, 2)]
+    #[case(8, 1, 3, 1)]
+    #[case(12, 0, 4, 1)]
+    fn at_reports_line_and_column(
+        #[case] offset: usize,
+        #[case] len: usize,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let error = ParseError::at(1, INPUT, &INPUT[offset..offset + len], "oops");
+
+        assert_eq!((error.line, error.column), (line, column));
+        assert_eq!(error.bytes, &INPUT[offset..offset + len]);
+    }
+
+    #[test]
+    fn within_rebases_onto_the_whole_input() {
+        let line = &INPUT[8..11];
+        let error = ParseError::at(1, line, &line[..1], "invalid direction").within(INPUT, line);
+
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn display_shows_location_and_offending_bytes() {
+        let error = ParseError::at(1, INPUT, &INPUT[8..9], "invalid direction");
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 3, column 1: invalid direction (found \"X\")"
+        );
+
+        let error = ParseError::eof(6, INPUT, "missing operator line");
+        assert_eq!(
+            error.to_string(),
+            "day 6, line 4, column 1: missing operator line (at end of input)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "slice does not point into the input")]
+    fn at_rejects_foreign_slices() {
+        ParseError::at(1, INPUT, b"L68", "oops");
+    }
+}
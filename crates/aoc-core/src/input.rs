@@ -0,0 +1,169 @@
+use std::{
+    ffi::OsString,
+    fmt::{self, Display},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory holding one `<puzzle-dir>/input.txt` per puzzle, e.g. a private
+/// checkout of the inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Reads the puzzle input of the calling crate, see [`Source::resolve`].
+///
+/// Pass the first command line argument from binaries; benches and tests call
+/// it without arguments.
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::input!(::std::option::Option::<::std::ffi::OsString>::None)
+    };
+    ($arg:expr) => {
+        $crate::input::Source::resolve(
+            $arg,
+            ::std::env::var_os($crate::input::INPUT_DIR_VAR),
+            env!("CARGO_PKG_NAME"),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+        )
+        .read()
+    };
+}
+
+/// Where the crate `name` lives below `puzzles/`. Crates of other years than
+/// [`DEFAULT_YEAR`](crate::DEFAULT_YEAR) are named `y2024-day-05-part-01`
+/// and live in `2024/day-05-part-01`.
+pub fn puzzle_dir(name: &str) -> String {
+    match name.strip_prefix('y').and_then(|rest| rest.split_once('-')) {
+        Some((year, rest)) if year.parse::<u16>().is_ok() => format!("{year}/{rest}"),
+        _ => name.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the first of: an explicit path (`-` for stdin),
+    /// `$AOC_INPUT_DIR/<puzzle-dir>/input.txt`, or `input.txt` next to the
+    /// crate manifest. None of these depend on the working directory.
+    pub fn resolve(
+        arg: Option<OsString>,
+        input_dir: Option<OsString>,
+        name: &str,
+        manifest_dir: &Path,
+    ) -> Self {
+        match (arg, input_dir) {
+            (Some(arg), _) if arg == "-" => Source::Stdin,
+            (Some(arg), _) => Source::File(arg.into()),
+            (None, Some(dir)) if !dir.is_empty() => {
+                Source::File(Path::new(&dir).join(puzzle_dir(name)).join("input.txt"))
+            }
+            _ => Source::File(manifest_dir.join("input.txt")),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        };
+
+        result.map_err(|error| InputError {
+            from: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub from: Source,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot read input from {}: {}", self.from, self.error)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::OsString, path::Path};
+
+    use rstest::rstest;
+
+    use super::{Source, puzzle_dir};
+
+    #[rstest]
+    #[case(Some("-"), Some("/inputs"), Source::Stdin)]
+    #[case(Some("mine.txt"), Some("/inputs"), Source::File("mine.txt".into()))]
+    #[case(None, Some("/inputs"), Source::File("/inputs/day-01-part-01/input.txt".into()))]
+    #[case(None, Some(""), Source::File("/repo/puzzles/day-01-part-01/input.txt".into()))]
+    #[case(None, None, Source::File("/repo/puzzles/day-01-part-01/input.txt".into()))]
+    fn resolve_prefers_argument_then_env_then_manifest_dir(
+        #[case] arg: Option<&str>,
+        #[case] input_dir: Option<&str>,
+        #[case] expected: Source,
+    ) {
+        let source = Source::resolve(
+            arg.map(OsString::from),
+            input_dir.map(OsString::from),
+            "day-01-part-01",
+            Path::new("/repo/puzzles/day-01-part-01"),
+        );
+
+        assert_eq!(source, expected);
+    }
+
+    #[rstest]
+    #[case("day-01-part-01", "day-01-part-01")]
+    #[case("y2024-day-05-part-02", "2024/day-05-part-02")]
+    #[case("yolo-day-05-part-02", "yolo-day-05-part-02")]
+    fn puzzle_dir_nests_other_years(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(puzzle_dir(name), expected);
+    }
+
+    #[test]
+    fn read_reports_the_missing_file() {
+        let error = Source::File("does/not/exist.txt".into())
+            .read()
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("cannot read input from does/not/exist.txt: ")
+        );
+    }
+
+    #[test]
+    fn macro_reads_from_the_manifest_dir() {
+        let input = crate::input!(Some(OsString::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/Cargo.toml"
+        ))))
+        .unwrap();
+        assert!(input.contains("name = \"aoc-core\""));
+    }
+}
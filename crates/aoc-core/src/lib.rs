@@ -0,0 +1,109 @@
+pub mod alloc;
+#[cfg(feature = "criterion")]
+pub mod bench;
+#[cfg(feature = "proptest")]
+pub mod differential;
+mod error;
+pub mod input;
+
+use std::{fmt::Display, str::FromStr};
+
+pub use error::ParseError;
+#[cfg(feature = "trace")]
+#[doc(hidden)]
+pub use tracing;
+
+/// The event this workspace was started for. Its crates keep the plain
+/// `day-XX-part-YY` names, see [`input::puzzle_dir`].
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// A single puzzle part, split into a parse phase and a solve phase so both
+/// can be timed on their own.
+pub trait Solution {
+    const YEAR: u16 = DEFAULT_YEAR;
+    const DAY: u8;
+    const PART: u8;
+    /// Whether `solve` spreads its work over the rayon pool itself.
+    const PARALLEL: bool = false;
+    /// Whether the examples of the description are solved like the real
+    /// input. Not when the puzzle changes a parameter for them, like the
+    /// number of pairs to connect.
+    const EXAMPLES: bool = true;
+
+    /// The parsed form of the puzzle input. May borrow from the raw input.
+    type Input<'a>;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn solve(input: Self::Input<'_>) -> Self::Output;
+
+    fn try_run(input: &str) -> Result<Self::Output, ParseError> {
+        Self::parse(input).map(Self::solve)
+    }
+
+    /// Like [`Solution::try_run`], but panics on malformed input.
+    fn run(input: &str) -> Self::Output {
+        Self::try_run(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Times the rest of the enclosing block as a `tracing` span, for the
+/// breakdown of `aoc` built with `--features trace`. Without the `trace`
+/// feature of this crate it expands to nothing.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! phase {
+    ($name:literal) => {
+        let _phase = $crate::tracing::info_span!($name).entered();
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! phase {
+    ($name:literal) => {};
+}
+
+/// Parses `digits`, a subslice of `input`, as a decimal number.
+pub fn parse_number<T: FromStr>(day: u8, input: &[u8], digits: &[u8]) -> Result<T, ParseError> {
+    std::str::from_utf8(digits)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| ParseError::at(day, input, digits, "expected a number"))
+}
+
+/// Checks that a byte-oriented puzzle input is valid UTF-8.
+pub fn from_utf8(day: u8, input: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(input).map_err(|e| {
+        let invalid = &input[e.valid_up_to()..];
+        let len = e.error_len().unwrap_or(invalid.len());
+        ParseError::at(day, input, &invalid[..len], "invalid UTF-8")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(b"42", Some(42))]
+    #[case(b"", None)]
+    #[case(b"4x2", None)]
+    #[case(b"-1", None)]
+    #[case(b"99999999999", None)]
+    fn parse_number(#[case] digits: &[u8], #[case] expected: Option<u32>) {
+        let result = super::parse_number::<u32>(3, digits, digits);
+
+        assert_eq!(result.ok(), expected);
+    }
+
+    #[test]
+    fn from_utf8_points_at_invalid_byte() {
+        let input = b"12\n3\xff4\n";
+        let error = super::from_utf8(6, input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.bytes, b"\xff");
+    }
+}
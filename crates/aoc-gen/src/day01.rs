@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+/// Dial rotations like `L68`, one per line.
+#[derive(Debug, Clone)]
+pub struct Rotations {
+    pub count: usize,
+    pub max_steps: usize,
+}
+
+impl Default for Rotations {
+    fn default() -> Self {
+        Self {
+            count: 4168,
+            max_steps: 999,
+        }
+    }
+}
+
+impl Rotations {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::with_capacity(self.count * 5);
+
+        for _ in 0..self.count {
+            let direction = if rng.bool() { 'L' } else { 'R' };
+            writeln!(out, "{direction}{}", rng.usize(1..=self.max_steps)).unwrap();
+        }
+
+        out
+    }
+}
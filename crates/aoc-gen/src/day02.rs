@@ -0,0 +1,41 @@
+use std::fmt::Write;
+
+/// A single line of comma separated id ranges like `11-22`.
+#[derive(Debug, Clone)]
+pub struct IdRanges {
+    pub count: usize,
+    /// Upper bound on the number of ids in a range, the solvers visit every one of them.
+    pub span: u64,
+    pub max_digits: u32,
+}
+
+impl Default for IdRanges {
+    fn default() -> Self {
+        Self {
+            count: 29,
+            span: 200_000,
+            max_digits: 10,
+        }
+    }
+}
+
+impl IdRanges {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::new();
+
+        for i in 0..self.count {
+            let digits = rng.u32(1..=self.max_digits);
+            let start = rng.u64(10u64.pow(digits - 1)..10u64.pow(digits));
+            let end = start + rng.u64(0..self.span);
+
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, "{start}-{end}").unwrap();
+        }
+
+        out.push('\n');
+        out
+    }
+}
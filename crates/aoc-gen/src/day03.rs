@@ -0,0 +1,30 @@
+/// Banks of battery joltages, one digit per battery.
+#[derive(Debug, Clone)]
+pub struct Banks {
+    pub count: usize,
+    /// Batteries per bank, part two needs at least twelve.
+    pub batteries: usize,
+}
+
+impl Default for Banks {
+    fn default() -> Self {
+        Self {
+            count: 200,
+            batteries: 100,
+        }
+    }
+}
+
+impl Banks {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::with_capacity(self.count * (self.batteries + 1));
+
+        for _ in 0..self.count {
+            out.extend((0..self.batteries).map(|_| rng.char('1'..='9')));
+            out.push('\n');
+        }
+
+        out
+    }
+}
@@ -0,0 +1,32 @@
+/// A grid of paper rolls `@` and empty floor `.`.
+#[derive(Debug, Clone)]
+pub struct Rolls {
+    pub width: usize,
+    pub height: usize,
+    /// Chance that a cell holds a roll.
+    pub density: f64,
+}
+
+impl Default for Rolls {
+    fn default() -> Self {
+        Self {
+            width: 136,
+            height: 136,
+            density: 0.65,
+        }
+    }
+}
+
+impl Rolls {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for _ in 0..self.height {
+            out.extend((0..self.width).map(|_| if rng.f64() < self.density { '@' } else { '.' }));
+            out.push('\n');
+        }
+
+        out
+    }
+}
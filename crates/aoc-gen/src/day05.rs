@@ -0,0 +1,51 @@
+use std::fmt::Write;
+
+/// Fresh ingredient id ranges, a blank line, then the available ids.
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    pub ranges: usize,
+    pub ids: usize,
+    pub max_id: u64,
+    pub span: u64,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self {
+            ranges: 187,
+            ids: 1000,
+            max_id: 560_000_000_000_000,
+            span: 2_000_000_000_000,
+        }
+    }
+}
+
+impl Inventory {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::new();
+
+        let ranges: Vec<(u64, u64)> = (0..self.ranges)
+            .map(|_| {
+                let start = rng.u64(1..=self.max_id);
+                (start, start + rng.u64(0..=self.span))
+            })
+            .collect();
+        for (start, end) in &ranges {
+            writeln!(out, "{start}-{end}").unwrap();
+        }
+
+        out.push('\n');
+
+        // ongeveer de helft valt in een range, anders is bijna alles bederfelijk
+        for _ in 0..self.ids {
+            let id = match rng.choice(&ranges) {
+                Some(&(start, end)) if rng.bool() => rng.u64(start..=end),
+                _ => rng.u64(1..=self.max_id),
+            };
+            writeln!(out, "{id}").unwrap();
+        }
+
+        out
+    }
+}
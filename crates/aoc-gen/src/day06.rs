@@ -0,0 +1,60 @@
+/// A worksheet of problems written in columns, with the operator below each.
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    pub problems: usize,
+    /// Numbers per problem.
+    pub rows: usize,
+    /// At most this many digits per number, part one multiplies whole columns.
+    pub max_digits: u32,
+}
+
+impl Default for Worksheet {
+    fn default() -> Self {
+        Self {
+            problems: 1000,
+            rows: 4,
+            max_digits: 3,
+        }
+    }
+}
+
+impl Worksheet {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut lines = vec![String::new(); self.rows + 1];
+
+        for problem in 0..self.problems {
+            let numbers: Vec<String> = (0..self.rows)
+                .map(|_| {
+                    let digits = rng.u32(1..=self.max_digits);
+                    rng.u64(10u64.pow(digits - 1)..10u64.pow(digits))
+                        .to_string()
+                })
+                .collect();
+            let width = numbers.iter().map(String::len).max().unwrap_or(1);
+            let right_aligned = rng.bool();
+
+            for (line, number) in lines.iter_mut().zip(&numbers) {
+                if problem > 0 {
+                    line.push(' ');
+                }
+                if right_aligned {
+                    line.push_str(&format!("{number:>width$}"));
+                } else {
+                    line.push_str(&format!("{number:<width$}"));
+                }
+            }
+
+            let operator = lines.last_mut().unwrap();
+            if problem > 0 {
+                operator.push(' ');
+            }
+            let symbol = if rng.bool() { '+' } else { '*' };
+            operator.push_str(&format!("{symbol:<width$}"));
+        }
+
+        let mut out = lines.join("\n");
+        out.push('\n');
+        out
+    }
+}
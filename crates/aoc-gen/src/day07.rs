@@ -0,0 +1,47 @@
+/// A tachyon manifold: the source `S` in the middle of the top row and
+/// splitters `^` on every other row, never next to each other.
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    pub width: usize,
+    pub height: usize,
+    /// Chance that a cell on a splitter row holds a splitter.
+    pub density: f64,
+}
+
+impl Default for Manifold {
+    fn default() -> Self {
+        Self {
+            width: 141,
+            height: 142,
+            density: 0.2,
+        }
+    }
+}
+
+impl Manifold {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for y in 0..self.height {
+            let mut row = vec![b'.'; self.width];
+            if y == 0 {
+                row[self.width / 2] = b'S';
+            } else if y % 2 == 0 {
+                let mut x = 1;
+                while x + 1 < self.width {
+                    if rng.f64() < self.density {
+                        row[x] = b'^';
+                        x += 1;
+                    }
+                    x += 1;
+                }
+            }
+
+            out.push_str(std::str::from_utf8(&row).unwrap());
+            out.push('\n');
+        }
+
+        out
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Write;
+
+/// Junction box positions `x,y,z`, one per line.
+#[derive(Debug, Clone)]
+pub struct JunctionBoxes {
+    /// Part two indexes the boxes with a `u16`.
+    pub count: usize,
+    pub extent: u32,
+}
+
+impl Default for JunctionBoxes {
+    fn default() -> Self {
+        Self {
+            count: 1000,
+            extent: 100_000,
+        }
+    }
+}
+
+impl JunctionBoxes {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::with_capacity(self.count * 18);
+
+        for _ in 0..self.count {
+            let [x, y, z] = [(); 3].map(|_| rng.u32(0..self.extent));
+            writeln!(out, "{x},{y},{z}").unwrap();
+        }
+
+        out
+    }
+}
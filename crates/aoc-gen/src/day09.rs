@@ -0,0 +1,97 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+/// Red tiles `x,y` in loop order, forming a closed polygon of horizontal and
+/// vertical edges.
+#[derive(Debug, Clone)]
+pub struct RedTiles {
+    /// Rounded up to a multiple of four.
+    pub corners: usize,
+    pub extent: u32,
+}
+
+impl Default for RedTiles {
+    fn default() -> Self {
+        Self {
+            corners: 496,
+            extent: 100_000,
+        }
+    }
+}
+
+impl RedTiles {
+    /// Builds an x-monotone polygon out of adjacent columns: each column has a
+    /// top above the middle and a bottom below it, so neighbours always
+    /// overlap and the outline never crosses itself.
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let columns = self.corners.div_ceil(4).max(1);
+        let middle = self.extent / 2;
+        assert!(
+            columns < middle as usize,
+            "{} corners do not fit in an extent of {}",
+            self.corners,
+            self.extent
+        );
+
+        let mut xs = BTreeSet::new();
+        while xs.len() < columns + 1 {
+            xs.insert(rng.u32(0..self.extent));
+        }
+        let xs: Vec<u32> = xs.into_iter().collect();
+
+        let mut distinct = |range: std::ops::Range<u32>| {
+            let mut previous = None;
+            (0..columns)
+                .map(|_| {
+                    let mut value = rng.u32(range.clone());
+                    while Some(value) == previous {
+                        value = rng.u32(range.clone());
+                    }
+                    previous = Some(value);
+                    value
+                })
+                .collect::<Vec<_>>()
+        };
+        let tops = distinct(middle + 1..self.extent);
+        let bottoms = distinct(0..middle);
+
+        let mut out = String::with_capacity(columns * 4 * 12);
+        for (i, &top) in tops.iter().enumerate() {
+            writeln!(out, "{},{top}", xs[i]).unwrap();
+            writeln!(out, "{},{top}", xs[i + 1]).unwrap();
+        }
+        for (i, &bottom) in bottoms.iter().enumerate().rev() {
+            writeln!(out, "{},{bottom}", xs[i + 1]).unwrap();
+            writeln!(out, "{},{bottom}", xs[i]).unwrap();
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedTiles;
+
+    #[test]
+    fn consecutive_tiles_share_a_row_or_column() {
+        let input = RedTiles {
+            corners: 40,
+            extent: 1000,
+        }
+        .generate(7);
+        let tiles: Vec<(u32, u32)> = input
+            .lines()
+            .map(|line| {
+                let (x, y) = line.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(tiles.len(), 40);
+        for (i, a) in tiles.iter().enumerate() {
+            let b = tiles[(i + 1) % tiles.len()];
+            assert!(a != &b && (a.0 == b.0 || a.1 == b.1), "{a:?} -> {b:?}");
+        }
+    }
+}
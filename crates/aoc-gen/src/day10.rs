@@ -0,0 +1,71 @@
+use std::fmt::Write;
+
+/// Machines like `[.##.] (3) (1,3) (2) {3,5,4,7}`. The diagram and joltages
+/// come from actual button presses, so every machine can be solved.
+#[derive(Debug, Clone)]
+pub struct Machines {
+    pub count: usize,
+    /// Indicator lights per machine, part two reads single digit indices.
+    pub lights: usize,
+    pub buttons: usize,
+    /// Upper bound on how often a button is pressed for the joltages.
+    pub max_presses: u32,
+}
+
+impl Default for Machines {
+    fn default() -> Self {
+        Self {
+            count: 172,
+            lights: 8,
+            buttons: 10,
+            max_presses: 30,
+        }
+    }
+}
+
+impl Machines {
+    pub fn generate(&self, seed: u64) -> String {
+        assert!(
+            (1..=10).contains(&self.lights),
+            "machines have between 1 and 10 lights"
+        );
+
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::new();
+
+        for _ in 0..self.count {
+            let buttons: Vec<Vec<usize>> = (0..self.buttons)
+                .map(|_| {
+                    let mut wiring: Vec<usize> = (0..self.lights).filter(|_| rng.bool()).collect();
+                    if wiring.is_empty() {
+                        wiring.push(rng.usize(0..self.lights));
+                    }
+                    wiring
+                })
+                .collect();
+
+            let mut lights = vec![false; self.lights];
+            let mut joltages = vec![0u32; self.lights];
+            for wiring in &buttons {
+                let toggled = rng.bool();
+                let presses = rng.u32(0..=self.max_presses);
+                for &light in wiring {
+                    lights[light] ^= toggled;
+                    joltages[light] += presses;
+                }
+            }
+
+            out.push('[');
+            out.extend(lights.iter().map(|&on| if on { '#' } else { '.' }));
+            out.push(']');
+            for wiring in &buttons {
+                let wiring: Vec<String> = wiring.iter().map(usize::to_string).collect();
+                write!(out, " ({})", wiring.join(",")).unwrap();
+            }
+            let joltages: Vec<String> = joltages.iter().map(u32::to_string).collect();
+            writeln!(out, " {{{}}}", joltages.join(",")).unwrap();
+        }
+
+        out
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt::Write;
+
+const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+/// Device outputs like `aaa: bbb ccc`, forming a layered graph that ends in
+/// `out`. A chain through `svr`, `you`, `fft` and `dac` makes sure both parts
+/// have at least one path.
+#[derive(Debug, Clone)]
+pub struct Devices {
+    /// Named devices besides `out`, at most 26³ - 1.
+    pub count: usize,
+    /// Path counts grow with the number of layers, not with the device count.
+    pub layers: usize,
+    pub max_outputs: usize,
+}
+
+impl Default for Devices {
+    fn default() -> Self {
+        Self {
+            count: 600,
+            layers: 16,
+            max_outputs: 3,
+        }
+    }
+}
+
+impl Devices {
+    pub fn generate(&self, seed: u64) -> String {
+        assert!(
+            self.layers >= 6,
+            "need six layers to order svr, you, fft and dac"
+        );
+        assert!(
+            self.count >= self.layers && self.count < 26 * 26 * 26,
+            "need one device per layer and unique names"
+        );
+
+        let mut rng = fastrand::Rng::with_seed(seed);
+
+        let mut names: Vec<String> = (0..26 * 26 * 26)
+            .map(|i: usize| {
+                let letter = |n: usize| char::from(b'a' + (n % 26) as u8);
+                [letter(i / 676), letter(i / 26), letter(i)]
+                    .iter()
+                    .collect()
+            })
+            .filter(|name: &String| !RESERVED.contains(&name.as_str()))
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(self.count - 4);
+
+        let mut layers: Vec<Vec<String>> = vec![Vec::new(); self.layers];
+        for (i, name) in names.into_iter().enumerate() {
+            layers[i % self.layers].push(name);
+        }
+        let chain = [
+            (0, "svr"),
+            (1, "you"),
+            (self.layers / 3, "fft"),
+            (2 * self.layers / 3, "dac"),
+        ];
+        for (layer, name) in chain {
+            layers[layer].insert(0, name.to_string());
+        }
+        layers.push(vec!["out".to_string()]);
+
+        let mut lines = Vec::with_capacity(self.count);
+        for (layer, next) in layers.iter().zip(&layers[1..]) {
+            for (i, device) in layer.iter().enumerate() {
+                let mut outputs: Vec<&str> = (0..rng.usize(1..=self.max_outputs))
+                    .map(|_| next[rng.usize(0..next.len())].as_str())
+                    .collect();
+                // het eerste apparaat van elke laag hoort bij de keten
+                if i == 0 {
+                    outputs.push(&next[0]);
+                }
+                outputs.sort_unstable();
+                outputs.dedup();
+
+                let mut line = format!("{device}:");
+                for output in outputs {
+                    write!(line, " {output}").unwrap();
+                }
+                lines.push(line);
+            }
+        }
+
+        rng.shuffle(&mut lines);
+        let mut out = lines.join("\n");
+        out.push('\n');
+        out
+    }
+}
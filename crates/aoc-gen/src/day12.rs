@@ -0,0 +1,90 @@
+use std::fmt::Write;
+
+/// Six present shapes of seven cells each, always 96 bytes like the real input.
+const SHAPES: &str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+.#.
+###
+
+2:
+.##
+###
+##.
+
+3:
+#.#
+###
+#.#
+
+4:
+###
+#..
+###
+
+5:
+##.
+.##
+###
+
+";
+
+/// The present shapes followed by regions like `12x34: 1 0 1 0 2 2`.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    pub count: usize,
+    /// How full a region is on average, around 1.0 half of them fit.
+    pub fill: f64,
+}
+
+impl Default for Regions {
+    fn default() -> Self {
+        Self {
+            count: 1000,
+            fill: 1.0,
+        }
+    }
+}
+
+impl Regions {
+    pub fn generate(&self, seed: u64) -> String {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut out = String::from(SHAPES);
+
+        for _ in 0..self.count {
+            // de parser verwacht precies twee cijfers per dimensie
+            let width = rng.usize(35..=50);
+            let height = rng.usize(35..=50);
+            let presents = (width * height) as f64 / 7.0 * self.fill;
+
+            write!(out, "{width}x{height}:").unwrap();
+            for _ in 0..SHAPES.matches(':').count() {
+                let count = presents / 6.0 * (0.8 + 0.4 * rng.f64());
+                write!(out, " {}", count as usize).unwrap();
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SHAPES;
+
+    #[test]
+    fn shapes_match_the_real_header() {
+        assert_eq!(SHAPES.len(), 96);
+        assert!(
+            SHAPES
+                .split("\n\n")
+                .all(|shape| shape.matches('#').count() == 7 || shape.is_empty())
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    time::Duration,
+};
+
+use aoc_core::DEFAULT_YEAR;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    manifest::{default_year, is_default_year},
+    report::Report,
+};
+
+const HEADER: &str =
+    "# Written by `aoc bench --save-baseline`. Mean time of a full run per solution.\n\n";
+
+/// Benchmark timings to compare later runs against.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Baseline {
+    #[serde(rename = "timing", default)]
+    pub timings: Vec<Timing>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Timing {
+    /// Left out for [`DEFAULT_YEAR`], like in the answer manifest.
+    #[serde(default = "default_year", skip_serializing_if = "is_default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub mean_ns: f64,
+}
+
+impl Baseline {
+    /// Takes the `total` benchmark of every solution in the report.
+    pub fn from_report(report: &Report) -> Self {
+        let timings = report
+            .benchmarks
+            .iter()
+            .filter(|m| m.phase == "total")
+            .map(|m| Timing {
+                year: m.year,
+                day: m.day,
+                part: m.part,
+                mean_ns: m.mean_ns,
+            })
+            .collect();
+
+        Self { timings }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, format!("{HEADER}{text}"))
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+
+    /// One delta per solution in the current baseline, then one for every
+    /// solution of `self` that is missing from it. Solutions missing from
+    /// `self` have no previous timing.
+    pub fn compare(&self, current: &Baseline) -> Vec<Delta> {
+        let deltas = current.timings.iter().map(|timing| Delta {
+            year: timing.year,
+            day: timing.day,
+            part: timing.part,
+            before_ns: self.find(timing).map(|before| before.mean_ns),
+            after_ns: Some(timing.mean_ns),
+        });
+        let missing = self
+            .timings
+            .iter()
+            .filter(|before| current.find(before).is_none())
+            .map(|before| Delta {
+                year: before.year,
+                day: before.day,
+                part: before.part,
+                before_ns: Some(before.mean_ns),
+                after_ns: None,
+            });
+
+        deltas.chain(missing).collect()
+    }
+
+    fn find(&self, timing: &Timing) -> Option<&Timing> {
+        self.timings.iter().find(|other| {
+            (other.year, other.day, other.part) == (timing.year, timing.day, timing.part)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Delta {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub before_ns: Option<f64>,
+    /// `None` when the solution is no longer benchmarked.
+    pub after_ns: Option<f64>,
+}
+
+impl Delta {
+    /// Relative change in percent, positive when slower.
+    pub fn percent(&self) -> Option<f64> {
+        let (before, after) = self.before_ns.zip(self.after_ns)?;
+        Some((after - before) / before * 100.0)
+    }
+
+    pub fn missing(&self) -> bool {
+        self.after_ns.is_none()
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.percent().is_some_and(|percent| percent > threshold)
+    }
+}
+
+impl Display for Delta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let duration = |ns: f64| Duration::from_secs_f64(ns / 1e9);
+        if self.year != DEFAULT_YEAR {
+            write!(f, "{} ", self.year)?;
+        }
+        write!(f, "day {:2} part {}: ", self.day, self.part)?;
+
+        match (self.before_ns, self.after_ns) {
+            (Some(before), Some(after)) => write!(
+                f,
+                "{:.2?} -> {:.2?} ({:+.1}%)",
+                duration(before),
+                duration(after),
+                (after - before) / before * 100.0
+            ),
+            (None, Some(after)) => write!(f, "{:.2?} (no baseline)", duration(after)),
+            (Some(before), None) => write!(f, "{:.2?} -> missing", duration(before)),
+            (None, None) => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Baseline, Delta, Timing};
+
+    fn timing(day: u8, part: u8, mean_ns: f64) -> Timing {
+        Timing {
+            year: 2025,
+            day,
+            part,
+            mean_ns,
+        }
+    }
+
+    #[test]
+    fn compare_matches_solutions_by_year_day_and_part() {
+        let before = Baseline {
+            timings: vec![
+                timing(8, 2, 2_000_000.0),
+                Timing {
+                    year: 2024,
+                    ..timing(9, 1, 100.0)
+                },
+            ],
+        };
+        let after = Baseline {
+            timings: vec![
+                timing(8, 2, 1_000_000.0),
+                timing(9, 1, 500.0),
+                Timing {
+                    year: 2024,
+                    ..timing(9, 1, 200.0)
+                },
+            ],
+        };
+
+        let deltas = before.compare(&after);
+
+        assert_eq!(deltas[0].percent(), Some(-50.0));
+        assert_eq!(deltas[1].percent(), None);
+        assert_eq!(deltas[2].percent(), Some(100.0));
+        assert_eq!(
+            deltas.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "day  8 part 2: 2.00ms -> 1.00ms (-50.0%)",
+                "day  9 part 1: 500.00ns (no baseline)",
+                "2024 day  9 part 1: 100.00ns -> 200.00ns (+100.0%)",
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_reports_solutions_missing_from_the_current_run() {
+        let before = Baseline {
+            timings: vec![timing(8, 1, 1_000_000.0), timing(8, 2, 2_000_000.0)],
+        };
+        let after = Baseline {
+            timings: vec![timing(8, 2, 2_000_000.0)],
+        };
+
+        let deltas = before.compare(&after);
+
+        assert_eq!(
+            deltas.iter().map(Delta::missing).collect::<Vec<_>>(),
+            [false, true]
+        );
+        assert_eq!(deltas[1].percent(), None);
+        assert!(!deltas[1].regressed(10.0));
+        assert_eq!(deltas[1].to_string(), "day  8 part 1: 1.00ms -> missing");
+    }
+
+    #[rstest]
+    #[case(Some(100.0), 110.0, false)]
+    #[case(Some(100.0), 110.1, true)]
+    #[case(Some(100.0), 50.0, false)]
+    #[case(None, 1e9, false)]
+    fn regressed_only_beyond_threshold(
+        #[case] before_ns: Option<f64>,
+        #[case] after_ns: f64,
+        #[case] expected: bool,
+    ) {
+        let delta = Delta {
+            year: 2025,
+            day: 1,
+            part: 1,
+            before_ns,
+            after_ns: Some(after_ns),
+        };
+
+        assert_eq!(delta.regressed(10.0), expected);
+    }
+
+    #[test]
+    fn baseline_roundtrips_through_toml() {
+        let baseline = Baseline {
+            timings: vec![
+                timing(1, 1, 12_345.5),
+                Timing {
+                    year: 2024,
+                    ..timing(1, 1, 100.0)
+                },
+            ],
+        };
+
+        let text = toml::to_string(&baseline).unwrap();
+
+        assert_eq!(text.matches("year").count(), 1);
+        assert_eq!(toml::from_str::<Baseline>(&text).unwrap(), baseline);
+    }
+}
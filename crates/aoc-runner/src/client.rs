@@ -0,0 +1,383 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ureq::{Agent, Body, http::Response};
+
+use crate::{
+    manifest::Manifest,
+    registry::{self, Solver},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides [`DEFAULT_BASE_URL`], e.g. to point at a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The value of the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "aoc-runner (github.com/jensk-dev/aoc-2025)";
+
+/// Talks to the Advent of Code website as the user owning the session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    pub fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}{page}", self.base_url)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+
+        body(&url, response)
+    }
+
+    /// The puzzle page, which includes part two once part one is solved.
+    pub fn page(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "");
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+
+        body(&url, response)
+    }
+
+    /// The JSON of a private leaderboard, `id` is the number in its URL.
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<String, String> {
+        let url = format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        );
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+
+        body(&url, response)
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = self.url(year, day, "/answer");
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+
+        let page = body(&url, response)?;
+        Verdict::parse(&page).ok_or_else(|| format!("{url} answered with an unknown page"))
+    }
+}
+
+fn body(url: &str, response: Result<Response<Body>, ureq::Error>) -> Result<String, String> {
+    let mut response = response.map_err(|e| format!("cannot reach {url}: {e}"))?;
+
+    let status = response.status();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("cannot read {url}: {e}"))?;
+
+    if !status.is_success() {
+        return Err(format!("{url} answered {status}: {}", body.trim()));
+    }
+
+    Ok(body)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// With a hint in which direction when the site gives one, and how long
+    /// until the next answer is accepted.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// Answered too recently, the next answer is accepted after this long.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict from the `<article>` of the response page.
+    pub fn parse(page: &str) -> Option<Self> {
+        let article = page
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        if article.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if article.contains("That's not the right answer") {
+            let hint = if article.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if article.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = article
+                .split_once("Please wait ")
+                .and_then(|(_, rest)| rest.split_once(" before trying again"))
+                .and_then(|(wait, _)| spelled_wait(wait));
+            Some(Verdict::Wrong { hint, wait })
+        } else if article.contains("You gave an answer too recently") {
+            let (_, rest) = article.split_once("You have ")?;
+            let (left, _) = rest.split_once(" left to wait")?;
+            wait(left).map(Verdict::Wait)
+        } else if article.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses `34s`, `1m 5s` and the like.
+fn wait(left: &str) -> Option<Duration> {
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |total, amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let seconds = match unit {
+                "s" => 1,
+                "m" => 60,
+                "h" => 3600,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(number.parse::<u64>().ok()? * seconds))
+        })
+}
+
+/// Parses `one minute`, `5 minutes` and the like.
+fn spelled_wait(wait: &str) -> Option<Duration> {
+    let (number, unit) = wait.split_once(' ')?;
+    let number = match number {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    let seconds = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+    Some(Duration::from_secs(number * seconds))
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { hint, wait } => {
+                write!(f, "wrong")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                match wait {
+                    Some(wait) => write!(f, ", the next answer is accepted in {wait:?}"),
+                    None => Ok(()),
+                }
+            }
+            Verdict::Wait(left) => write!(f, "answered too recently, wait {left:?}"),
+            Verdict::WrongLevel => write!(f, "this part is solved already or still locked"),
+        }
+    }
+}
+
+/// Submits the answer for `input` unless the manifest already knows it, and
+/// records it when the site accepts it.
+pub fn submit(
+    client: &Client,
+    manifest: &mut Manifest,
+    solver: &Solver,
+    input: &Path,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if let Some(known) = manifest
+        .entries(solver.year, solver.day, solver.part)
+        .find(|entry| entry.input == input)
+    {
+        return if known.sha256 == manifest.hash(solver.year, solver.day, solver.part, answer) {
+            Ok(Verdict::Correct)
+        } else {
+            Err(format!(
+                "{}: {answer} differs from the accepted answer",
+                solver.name
+            ))
+        };
+    }
+
+    let verdict = client.submit(solver.year, solver.day, solver.part, answer)?;
+    if verdict == Verdict::Correct {
+        manifest.record(
+            solver.year,
+            solver.day,
+            solver.part,
+            input.to_path_buf(),
+            answer,
+        );
+    }
+
+    Ok(verdict)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Download {
+    /// Relative to the root, every crate of the day that got a new `input.txt`.
+    pub written: Vec<PathBuf>,
+    /// Whether the input had to come from the network.
+    pub fetched: bool,
+}
+
+/// Makes sure every crate of `day` below `root` has its `input.txt`. Existing
+/// inputs are kept and reused for the other part, so the network is only hit
+/// for days without any input unless `force` is set.
+pub fn download(
+    client: &Client,
+    root: &Path,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<Download, String> {
+    let solvers: Vec<&Solver> = registry::select(&[year], &[day], &[]).collect();
+    if solvers.is_empty() {
+        return Err(format!("there is no crate for {year} day {day}"));
+    }
+
+    let cached = if force {
+        None
+    } else {
+        solvers
+            .iter()
+            .find_map(|solver| fs::read_to_string(root.join(solver.input())).ok())
+            .filter(|input| !input.is_empty())
+    };
+    let fetched = cached.is_none();
+    let input = match cached {
+        Some(input) => input,
+        None => client.input(year, day)?,
+    };
+
+    let mut written = Vec::new();
+    for solver in solvers {
+        let path = solver.input();
+        let full = root.join(&path);
+        if !force && fs::metadata(&full).is_ok_and(|meta| meta.len() > 0) {
+            continue;
+        }
+
+        write(&full, &input).map_err(|e| format!("cannot write {}: {e}", full.display()))?;
+        written.push(path);
+    }
+
+    Ok(Download { written, fetched })
+}
+
+fn write(path: &Path, input: &str) -> io::Result<()> {
+    let dir = path.parent().expect("input.txt lives in a crate directory");
+    if !dir.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "the crate directory does not exist",
+        ));
+    }
+
+    fs::write(path, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{Hint, Verdict, spelled_wait, wait};
+
+    fn wrong(hint: Option<Hint>, seconds: u64) -> Verdict {
+        Verdict::Wrong {
+            hint,
+            wait: Some(Duration::from_secs(seconds)),
+        }
+    }
+
+    #[rstest]
+    #[case(include_str!("../tests/fixtures/answer/correct.html"), Verdict::Correct)]
+    #[case(include_str!("../tests/fixtures/answer/too_high.html"), wrong(Some(Hint::TooHigh), 60))]
+    #[case(include_str!("../tests/fixtures/answer/too_low.html"), wrong(Some(Hint::TooLow), 60))]
+    #[case(include_str!("../tests/fixtures/answer/wrong.html"), wrong(None, 300))]
+    #[case(include_str!("../tests/fixtures/answer/wait.html"), Verdict::Wait(Duration::from_secs(65)))]
+    #[case(include_str!("../tests/fixtures/answer/wrong_level.html"), Verdict::WrongLevel)]
+    fn parse_recorded_pages(#[case] page: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::parse(page), Some(expected));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_pages() {
+        assert_eq!(
+            Verdict::parse("<main><article><p>Hello</p></article></main>"),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case("34s", Some(34))]
+    #[case("1m 5s", Some(65))]
+    #[case("2h 0m 1s", Some(7201))]
+    #[case("soon", None)]
+    fn wait_parses_minutes_and_seconds(#[case] left: &str, #[case] expected: Option<u64>) {
+        assert_eq!(wait(left), expected.map(Duration::from_secs));
+    }
+
+    #[rstest]
+    #[case("one minute", Some(60))]
+    #[case("5 minutes", Some(300))]
+    #[case("30 seconds", Some(30))]
+    #[case("a while", None)]
+    fn spelled_wait_parses_the_wrong_answer_page(
+        #[case] wait: &str,
+        #[case] expected: Option<u64>,
+    ) {
+        assert_eq!(spelled_wait(wait), expected.map(Duration::from_secs));
+    }
+}
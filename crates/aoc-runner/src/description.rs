@@ -0,0 +1,526 @@
+use std::{fs, io, path::PathBuf};
+
+use crate::registry::{self, workspace_root};
+
+/// Converts a puzzle page to one plain Markdown text per unlocked part, in the
+/// style of the `description.md` files: headers and paragraphs on their own
+/// line, `<pre>` blocks verbatim and list items indented by four spaces.
+/// Emphasis outside the blocks is kept as `*42*`, for [`examples`].
+pub fn parse(page: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        parts.push(convert(&article[..end]));
+        rest = &article[end..];
+    }
+
+    parts
+}
+
+fn convert(html: &str) -> String {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut list = Vec::new();
+    let mut in_pre = false;
+
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            text.push_str(rest);
+            break;
+        };
+        text.push_str(&rest[..start]);
+
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end + 1);
+        let tag = &rest[start + 1..end.saturating_sub(1).max(start + 1)];
+        rest = &rest[end..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("pre", false) => {
+                text.clear();
+                in_pre = true;
+            }
+            ("pre", true) => {
+                blocks.push(decode(text.trim_end_matches('\n')));
+                text.clear();
+                in_pre = false;
+            }
+            ("h2" | "p" | "li" | "ul", false) => text.clear(),
+            ("h2" | "p", true) => {
+                blocks.push(collapse(&decode(&text)));
+                text.clear();
+            }
+            ("li", true) => {
+                list.push(format!("    {}", collapse(&decode(&text))));
+                text.clear();
+            }
+            ("ul", true) => {
+                blocks.push(list.join("\n"));
+                list.clear();
+                text.clear();
+            }
+            ("br", _) if in_pre => text.push('\n'),
+            ("em", _) if !in_pre => text.push('*'),
+            _ => {}
+        }
+    }
+
+    let mut markdown = blocks
+        .into_iter()
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((entity(&rest[1..end])?, end)));
+        match entity {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: String,
+}
+
+enum Piece {
+    Input(String),
+    Answer(String),
+}
+
+/// Every example input of a description with the answer the text emphasizes
+/// for it, the last one before the next input. Part two usually starts with
+/// answers for the example of part one, so those go with the first input of
+/// `part_one`. Descriptions without emphasis have no answers, so no examples.
+pub fn examples(description: &str, part_one: &str) -> Vec<Example> {
+    let mut input = pieces(part_one).into_iter().find_map(|piece| match piece {
+        Piece::Input(input) => Some(input),
+        Piece::Answer(_) => None,
+    });
+    let mut answer = None;
+    let mut examples = Vec::new();
+
+    for piece in pieces(description) {
+        match piece {
+            Piece::Input(next) => {
+                if let (Some(input), Some(answer)) = (input.replace(next), answer.take()) {
+                    examples.push(Example { input, answer });
+                }
+            }
+            Piece::Answer(next) => answer = Some(next),
+        }
+    }
+    if let (Some(input), Some(answer)) = (input, answer) {
+        examples.push(Example { input, answer });
+    }
+
+    examples
+}
+
+/// The example inputs and answers in the order of the text. An input is the
+/// block after a paragraph like "For example:", up to the next paragraph of
+/// prose, so it can contain empty lines.
+fn pieces(description: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut block: Option<Vec<&str>> = None;
+    let mut introduced = false;
+
+    for paragraph in paragraphs(description) {
+        if let [line] = paragraph[..]
+            && is_prose(line)
+        {
+            pieces.extend(block.take().map(|lines| Piece::Input(unwrap(&lines))));
+            pieces.extend(answer(line).map(Piece::Answer));
+            introduced = introduces_example(line);
+            continue;
+        }
+
+        if is_list(&paragraph) {
+            pieces.extend(block.take().map(|lines| Piece::Input(unwrap(&lines))));
+            let answers = paragraph.iter().filter_map(|line| answer(line));
+            pieces.extend(answers.map(Piece::Answer));
+        } else if let Some(block) = &mut block {
+            block.push("");
+            block.extend(&paragraph);
+        } else if introduced {
+            block = Some(paragraph);
+        }
+        introduced = false;
+    }
+    pieces.extend(block.map(|lines| Piece::Input(unwrap(&lines))));
+
+    pieces
+}
+
+fn paragraphs(text: &str) -> Vec<Vec<&str>> {
+    let mut paragraphs = vec![Vec::new()];
+    for line in text.lines() {
+        match line.trim().is_empty() {
+            true => paragraphs.push(Vec::new()),
+            false => paragraphs.last_mut().unwrap().push(line),
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    paragraphs
+}
+
+/// A sentence ending in punctuation, unlike the lines of an example, which
+/// can end in `.` but have no words.
+fn is_prose(line: &str) -> bool {
+    let word = |word: &&str| {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        !word.is_empty() && word.chars().all(char::is_alphabetic)
+    };
+    let words: Vec<&str> = line.split(' ').collect();
+
+    line.ends_with(['.', ':', '?', '!', ')', '"'])
+        && words.windows(2).any(|pair| pair.iter().all(word))
+}
+
+fn is_list(lines: &[&str]) -> bool {
+    lines.iter().all(|line| line.starts_with("    "))
+}
+
+fn sentences(paragraph: &str) -> impl Iterator<Item = &str> {
+    paragraph
+        .split_inclusive(['.', '?', '!'])
+        .map(str::trim)
+        .filter(|sentence| !sentence.is_empty())
+}
+
+/// "For example:" or "Consider again the example from before:", but not
+/// "In this example, the beam extends downward:" before a diagram.
+fn introduces_example(paragraph: &str) -> bool {
+    let Some(sentence) = sentences(paragraph).last() else {
+        return false;
+    };
+    let sentence = sentence.to_lowercase();
+
+    sentence.ends_with(':')
+        && if sentence.starts_with("for example") {
+            !sentence.contains(|c: char| c.is_ascii_digit())
+        } else {
+            sentence.contains("example")
+                && ["again", "from before", "above example"]
+                    .iter()
+                    .any(|phrase| sentence.contains(phrase))
+        }
+}
+
+/// The last emphasized answer of a line, like `*4277556*` in "the grand
+/// total is 33210 * 490 = *4277556*". Emphasized words, like "the *actual*
+/// password", are no answer.
+fn answer(line: &str) -> Option<String> {
+    let mut answer = None;
+    let mut rest = line;
+    while let Some(start) = rest.find('*') {
+        let after = &rest[start + 1..];
+        let span = after
+            .find('*')
+            .map(|end| &after[..end])
+            .filter(|span| !span.is_empty() && !span.contains(char::is_whitespace));
+
+        match span {
+            Some(span) => {
+                if !span.chars().all(char::is_alphabetic) {
+                    answer = Some(span.to_string());
+                }
+                rest = &after[span.len() + 1..];
+            }
+            None => rest = after,
+        }
+    }
+    answer
+}
+
+/// The lines of a block, with a line that ends in a comma joined to the next
+/// one, as the example of day 2 is wrapped for legibility.
+fn unwrap(lines: &[&str]) -> String {
+    let mut input = String::new();
+    for line in lines {
+        input.push_str(line);
+        if !line.ends_with(',') {
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// Writes part one into the part 1 crate and part two, once unlocked, into
+/// the part 2 crate, keeping the name of an existing description file.
+pub fn write(year: u16, day: u8, parts: &[String]) -> io::Result<Vec<PathBuf>> {
+    let root = workspace_root();
+    let mut written = Vec::new();
+
+    for (part, markdown) in (1..).zip(parts) {
+        let Some(solver) = registry::select(&[year], &[day], &[part]).next() else {
+            continue;
+        };
+
+        let dir = root.join(solver.dir());
+        let path = ["description.md", "description.txt", "description"]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+            .unwrap_or_else(|| dir.join("description.md"));
+
+        fs::write(&path, markdown)?;
+        written.push(path.strip_prefix(&root).unwrap_or(&path).to_path_buf());
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{Example, answer, decode, examples, parse};
+
+    const PAGE: &str = indoc! {r#"
+        <main>
+        <script>window.addEventListener('click', function(e) {});</script>
+        <article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2><p>The safe has a dial with
+        only an arrow on it; around the dial are the numbers <code>0</code> through <code>99</code>.</p>
+        <p>For example:</p>
+        <pre><code>L68
+        L30
+        </code></pre>
+        <ul>
+        <li>The dial starts by pointing at <code>50</code>.</li>
+        <li>The dial is rotated <code>L68</code> to point at <code>82</code>.</li>
+        </ul>
+        <p>The password in this example is <code><em>3</em></code>.</p>
+        <p>What's the <em>actual</em> password &amp; the <a href="/2025/day/1/input">input</a>?</p>
+        </article>
+        <p>Your puzzle answer was <code>1031</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Count clicks on <code>0</code> &lt;every&gt; time.</p>
+        <pre><code>x=<em>&lt;1&gt;</em>
+        </code></pre>
+        <p>Now the password in the above example is <code><em>6</em></code>.</p>
+        </article>
+        </main>
+    "#};
+
+    #[test]
+    fn parse_converts_each_part() {
+        let parts = parse(PAGE);
+
+        assert_eq!(
+            parts,
+            [
+                indoc! {"
+                    --- Day 1: Secret Entrance ---
+
+                    The safe has a dial with only an arrow on it; around the dial are the numbers 0 through 99.
+
+                    For example:
+
+                    L68
+                    L30
+
+                        The dial starts by pointing at 50.
+                        The dial is rotated L68 to point at 82.
+
+                    The password in this example is *3*.
+
+                    What's the *actual* password & the input?
+                "},
+                indoc! {"
+                    --- Part Two ---
+
+                    Count clicks on 0 <every> time.
+
+                    x=<1>
+
+                    Now the password in the above example is *6*.
+                "},
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_without_part_two() {
+        let page = PAGE.split("<p>Your puzzle answer").next().unwrap();
+
+        assert_eq!(parse(page).len(), 1);
+    }
+
+    #[test]
+    fn examples_of_a_converted_page() {
+        let parts = parse(PAGE);
+
+        assert_eq!(examples(&parts[0], &parts[0]), [example("L68\nL30\n", "3")]);
+        assert_eq!(examples(&parts[1], &parts[0]), [example("L68\nL30\n", "6")]);
+    }
+
+    const PART_ONE: &str = indoc! {"
+        --- Day 1: Secret Entrance ---
+
+        For example:
+
+        L68
+        L30
+
+        In this example, the dial points at 0 a total of *3* times.
+
+        Analyzing the example from before, with two dials:
+
+            Dial 1 starts at *50*.
+
+        In this example, the new password would be *6*.
+
+        What's the *actual* password?
+    "};
+
+    fn example(input: &str, answer: &str) -> Example {
+        Example {
+            input: input.to_string(),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn examples_of_part_one() {
+        assert_eq!(examples(PART_ONE, PART_ONE), [example("L68\nL30\n", "6")]);
+    }
+
+    #[test]
+    fn examples_reuse_the_input_of_part_one() {
+        let part_two = indoc! {"
+            --- Part Two ---
+
+            Following the same rotations as in the above example, the dial points at 0 a few extra times. In this example, the new password is *7*.
+
+            Consider again the example from before:
+
+            R5
+
+            L5
+
+            This now produces *2*.
+        "};
+
+        assert_eq!(
+            examples(part_two, PART_ONE),
+            [example("L68\nL30\n", "7"), example("R5\n\nL5\n", "2")]
+        );
+    }
+
+    #[test]
+    fn examples_join_wrapped_lines() {
+        let description = indoc! {"
+            For example:
+
+            11-22,95-115,
+            998-1012
+
+            Adding up all the invalid IDs in this example produces *1227775554*.
+        "};
+
+        assert_eq!(
+            examples(description, description),
+            [example("11-22,95-115,998-1012\n", "1227775554")]
+        );
+    }
+
+    #[test]
+    fn examples_skip_diagrams_after_prose() {
+        let description = indoc! {"
+            For example, suppose you have the following map:
+
+            ..@@.
+
+            For example, from 3 you can go to:
+
+            ..x..
+
+            In this example, there are *13* rolls.
+        "};
+
+        assert_eq!(
+            examples(description, description),
+            [example("..@@.\n", "13")]
+        );
+    }
+
+    #[test]
+    fn examples_need_an_emphasized_answer() {
+        let description = PART_ONE.replace('*', "");
+
+        assert_eq!(examples(&description, &description), []);
+    }
+
+    #[test]
+    fn answer_is_the_last_emphasized_value() {
+        assert_eq!(
+            answer("123 * 45 * 6 = *33210*, and *8544* is *at least* the *actual* one"),
+            Some("8544".to_string())
+        );
+        assert_eq!(answer("The *actual* password is 3."), None);
+    }
+
+    #[test]
+    fn decode_handles_named_and_numeric_entities() {
+        assert_eq!(
+            decode("&lt;a&gt; &#39;b&#x27; &amp;amp; & c"),
+            "<a> 'b' &amp; & c"
+        );
+    }
+}
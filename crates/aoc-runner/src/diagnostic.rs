@@ -0,0 +1,77 @@
+use std::fmt::{Display, Write};
+
+use aoc_core::ParseError;
+
+/// Renders the offending line with a caret under the bytes the parser choked on.
+pub fn render(origin: impl Display, input: &str, error: &ParseError) -> String {
+    let gutter = error.line.to_string().len();
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "{:gutter$}--> {}:{}:{}",
+        "", origin, error.line, error.column
+    )
+    .unwrap();
+
+    let Some(line) = input.lines().nth(error.line - 1) else {
+        return out;
+    };
+
+    let marked = error
+        .bytes
+        .split(|&c| c == b'\n')
+        .next()
+        .map_or(0, <[u8]>::len)
+        .max(1);
+
+    writeln!(out, "{:gutter$} |", "").unwrap();
+    writeln!(out, "{} | {line}", error.line).unwrap();
+    writeln!(
+        out,
+        "{:gutter$} | {:pad$}{}",
+        "",
+        "",
+        "^".repeat(marked),
+        pad = error.column - 1
+    )
+    .unwrap();
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::ParseError;
+    use indoc::indoc;
+
+    use super::render;
+
+    #[test]
+    fn render_points_at_offending_bytes() {
+        let input = "R12\nL5\nX7\n";
+        let error = ParseError::at(1, input.as_bytes(), &input.as_bytes()[7..8], "bad");
+
+        let expected = indoc! {"
+             --> input.txt:3:1
+              |
+            3 | X7
+              | ^
+        "};
+        assert_eq!(render("input.txt", input, &error), expected);
+    }
+
+    #[test]
+    fn render_marks_end_of_input() {
+        let input = "12x05: 1 0\n4x4";
+        let error = ParseError::eof(12, input.as_bytes(), "truncated");
+
+        let expected = indoc! {"
+             --> input.txt:2:4
+              |
+            2 | 4x4
+              |    ^
+        "};
+        assert_eq!(render("input.txt", input, &error), expected);
+    }
+}
@@ -0,0 +1,346 @@
+use std::{cmp::Reverse, collections::BTreeMap, time::Duration};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::table;
+
+/// A private leaderboard as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub last_star_ts: i64,
+    /// Day to part to star.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star(&self, day: u8, part: u8) -> Option<i64> {
+        self.completion_day_level
+            .get(&day)?
+            .get(&part)
+            .map(|star| star.get_star_ts)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Scoring {
+    /// The site's own: N points for the first star of a puzzle, N - 1 for the second...
+    Local,
+    /// Number of stars, ties broken by who got the last one first
+    Stars,
+    /// Local scoring on the time between part 1 and part 2 of a day
+    Delta,
+}
+
+/// When both stars of one day were got, relative to the puzzle unlock.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Solve {
+    pub member: String,
+    pub day: u8,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Solve {
+    pub fn delta(&self) -> Option<Duration> {
+        Some(self.part2? - self.part1?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Standing {
+    pub member: String,
+    pub score: u64,
+    pub stars: u32,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("not a leaderboard: {e}"))
+    }
+
+    /// Puzzles unlock at midnight EST, which is 05:00 UTC.
+    pub fn unlock(&self, day: u8) -> Result<i64, String> {
+        let year: i64 = self
+            .event
+            .parse()
+            .map_err(|_| format!("event {:?} is not a year", self.event))?;
+
+        Ok(days_from_civil(year, 12, day.into()) * 86_400 + 5 * 3_600)
+    }
+
+    /// Every day a member got at least one star for, by day and then name.
+    pub fn timelines(&self) -> Result<Vec<Solve>, String> {
+        let mut solves = Vec::new();
+        for member in self.members.values() {
+            for &day in member.completion_day_level.keys() {
+                let unlock = self.unlock(day)?;
+                let since_unlock = |part| {
+                    member
+                        .star(day, part)
+                        .map(|ts| Duration::from_secs(ts.saturating_sub(unlock).max(0) as u64))
+                };
+
+                solves.push(Solve {
+                    member: member.display_name(),
+                    day,
+                    part1: since_unlock(1),
+                    part2: since_unlock(2),
+                });
+            }
+        }
+
+        solves.sort_by(|a, b| (a.day, &a.member).cmp(&(b.day, &b.member)));
+        Ok(solves)
+    }
+
+    pub fn rank(&self, scoring: Scoring) -> Vec<Standing> {
+        let mut scores: BTreeMap<u64, u64> = self.members.values().map(|m| (m.id, 0)).collect();
+
+        match scoring {
+            Scoring::Local => {
+                for (day, part) in (1..=25).flat_map(|day| [(day, 1), (day, 2)]) {
+                    self.award(&mut scores, |member| member.star(day, part));
+                }
+            }
+            Scoring::Stars => {
+                for member in self.members.values() {
+                    scores.insert(member.id, member.stars.into());
+                }
+            }
+            Scoring::Delta => {
+                for day in 1..=25 {
+                    self.award(&mut scores, |member| {
+                        Some(member.star(day, 2)? - member.star(day, 1)?)
+                    });
+                }
+            }
+        }
+
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                Reverse(scores[&member.id]),
+                Reverse(member.stars),
+                member.last_star_ts,
+                member.display_name(),
+            )
+        });
+
+        members
+            .into_iter()
+            .map(|member| Standing {
+                member: member.display_name(),
+                score: scores[&member.id],
+                stars: member.stars,
+            })
+            .collect()
+    }
+
+    /// Gives N points to the member with the lowest key, N - 1 to the next and
+    /// so on, N being the number of members.
+    fn award(&self, scores: &mut BTreeMap<u64, u64>, key: impl Fn(&Member) -> Option<i64>) {
+        let mut ranked: Vec<(i64, u64)> = self
+            .members
+            .values()
+            .filter_map(|member| Some((key(member)?, member.id)))
+            .collect();
+        ranked.sort_unstable();
+
+        let n = self.members.len() as u64;
+        for (place, (_, id)) in (0..).zip(ranked) {
+            *scores.get_mut(&id).unwrap() += n - place;
+        }
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn clock(duration: Option<Duration>) -> String {
+    let Some(duration) = duration else {
+        return "-".to_string();
+    };
+
+    let seconds = duration.as_secs();
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+pub fn render_ranking(standings: &[Standing], markdown: bool) -> String {
+    let headers = ["Rank", "Member", "Score", "Stars"];
+    let cells: Vec<Vec<String>> = (1..)
+        .zip(standings)
+        .map(|(rank, standing)| {
+            vec![
+                rank.to_string(),
+                standing.member.clone(),
+                standing.score.to_string(),
+                standing.stars.to_string(),
+            ]
+        })
+        .collect();
+
+    if markdown {
+        table::markdown(&headers, &cells)
+    } else {
+        table::grid(&headers, &cells, None)
+    }
+}
+
+pub fn render_timelines(solves: &[Solve], markdown: bool) -> String {
+    let headers = ["Day", "Member", "Part 1", "Part 2", "Delta"];
+    let cells: Vec<Vec<String>> = solves
+        .iter()
+        .map(|solve| {
+            vec![
+                solve.day.to_string(),
+                solve.member.clone(),
+                clock(solve.part1),
+                clock(solve.part2),
+                clock(solve.delta()),
+            ]
+        })
+        .collect();
+
+    if markdown {
+        table::markdown(&headers, &cells)
+    } else {
+        table::grid(&headers, &cells, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use indoc::indoc;
+    use rstest::rstest;
+
+    use super::{Leaderboard, Scoring, Solve, days_from_civil, render_ranking, render_timelines};
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::parse(include_str!("../tests/fixtures/leaderboard.json")).unwrap()
+    }
+
+    fn ranking(scoring: Scoring) -> Vec<(String, u64)> {
+        leaderboard()
+            .rank(scoring)
+            .into_iter()
+            .map(|standing| (standing.member, standing.score))
+            .collect()
+    }
+
+    #[test]
+    fn local_scoring_matches_the_site() {
+        let leaderboard = leaderboard();
+
+        for standing in leaderboard.rank(Scoring::Local) {
+            let member = leaderboard
+                .members
+                .values()
+                .find(|member| member.display_name() == standing.member)
+                .unwrap();
+            assert_eq!(standing.score, member.local_score, "{}", standing.member);
+        }
+    }
+
+    #[rstest]
+    #[case(Scoring::Local, [("Alice", 14), ("Bob", 11), ("(anonymous user #1003)", 2), ("Carol", 0)])]
+    #[case(Scoring::Stars, [("Alice", 4), ("Bob", 3), ("(anonymous user #1003)", 1), ("Carol", 0)])]
+    #[case(Scoring::Delta, [("Alice", 8), ("Bob", 3), ("(anonymous user #1003)", 0), ("Carol", 0)])]
+    fn rank_orders_by_score(#[case] scoring: Scoring, #[case] expected: [(&str, u64); 4]) {
+        let expected: Vec<(String, u64)> = expected
+            .into_iter()
+            .map(|(name, score)| (name.to_string(), score))
+            .collect();
+
+        assert_eq!(ranking(scoring), expected);
+    }
+
+    #[test]
+    fn timelines_are_relative_to_the_unlock() {
+        let solves = leaderboard().timelines().unwrap();
+
+        assert_eq!(solves.len(), 5);
+        assert_eq!(
+            solves[2],
+            Solve {
+                member: "Bob".to_string(),
+                day: 1,
+                part1: Some(Duration::from_secs(3 * 60)),
+                part2: Some(Duration::from_secs(20 * 60)),
+            }
+        );
+        assert_eq!(solves[2].delta(), Some(Duration::from_secs(17 * 60)));
+        assert_eq!(solves[4].delta(), None);
+    }
+
+    #[test]
+    fn unlock_is_midnight_eastern() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(leaderboard.unlock(1), Ok(1_764_565_200));
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+    }
+
+    #[test]
+    fn renders_terminal_and_markdown_tables() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(
+            render_timelines(&leaderboard.timelines().unwrap()[..2], false),
+            indoc! {"
+                Day |                 Member |  Part 1 |  Part 2 |   Delta
+                ----+------------------------+---------+---------+--------
+                  1 | (anonymous user #1003) | 2:00:00 |       - |       -
+                  1 |                  Alice | 0:05:00 | 0:12:00 | 0:07:00
+            "}
+        );
+        assert_eq!(
+            render_ranking(&leaderboard.rank(Scoring::Delta)[..2], true),
+            indoc! {"
+                | Rank | Member | Score | Stars |
+                |---:|---:|---:|---:|
+                | 1 | Alice | 8 | 4 |
+                | 2 | Bob | 3 | 3 |
+            "}
+        );
+    }
+}
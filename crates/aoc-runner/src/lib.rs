@@ -0,0 +1,660 @@
+pub mod baseline;
+pub mod client;
+pub mod description;
+pub mod diagnostic;
+pub mod leaderboard;
+pub mod manifest;
+pub mod registry;
+pub mod repeat;
+pub mod report;
+pub mod scaffold;
+pub mod table;
+#[cfg(feature = "trace")]
+pub mod trace;
+
+use std::{env, ffi::OsString, fs, path::PathBuf, process, thread};
+
+use aoc_core::{DEFAULT_YEAR, input::Source};
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    baseline::Baseline,
+    client::{Client, Verdict},
+    leaderboard::{Leaderboard, Scoring},
+    manifest::Manifest,
+    registry::{Solver, workspace_root},
+    repeat::Job,
+    report::Report,
+    table::Row,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Run only these years (repeatable)
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: Vec<u16>,
+
+    /// Run only these days (repeatable)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Vec<u8>,
+
+    /// Run only these parts (repeatable)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Vec<u8>,
+
+    /// Run every registered solution
+    #[arg(short, long, conflicts_with = "day")]
+    pub all: bool,
+
+    /// Read the input from this file instead, `-` for stdin (needs a single solution)
+    #[arg(short, long, conflicts_with_all = ["check", "record"])]
+    pub input: Option<OsString>,
+
+    /// Compare the answers against answers.toml instead of printing them
+    #[arg(long, conflicts_with = "record")]
+    pub check: bool,
+
+    /// Store the answers in answers.toml
+    #[arg(long)]
+    pub record: bool,
+
+    /// Run every solution this many times and show the min, median and p95
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["check", "record"]
+    )]
+    pub repeat: Option<u32>,
+
+    /// Run the solutions concurrently on the rayon pool, the ones that use it
+    /// themselves afterwards one at a time
+    #[arg(long, requires = "repeat")]
+    pub concurrent: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Collect the criterion results of every solution into JSON and a Markdown table
+    Bench(BenchArgs),
+    /// Fetch puzzle inputs into the crates that do not have one yet
+    Download(DownloadArgs),
+    /// Submit an answer and store it in answers.toml when it is accepted
+    Submit(SubmitArgs),
+    /// Convert a puzzle page to the description files of its crates
+    Describe(DescribeArgs),
+    /// Rank a private leaderboard and show when every star was got
+    Leaderboard(LeaderboardArgs),
+    /// Create the crate of a new day or part and register it in the workspace
+    Scaffold(ScaffoldArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Run `cargo bench` for the whole workspace first
+    #[arg(long)]
+    pub run: bool,
+
+    /// Write the JSON report here [default: target/criterion/report.json]
+    #[arg(long)]
+    pub json: Option<PathBuf>,
+
+    /// Write the Markdown table here instead of printing it
+    #[arg(long)]
+    pub markdown: Option<PathBuf>,
+
+    /// Store the mean time of every solution as the new baseline
+    #[arg(long, conflicts_with = "check")]
+    pub save_baseline: bool,
+
+    /// Compare against the baseline and fail when a solution got slower
+    #[arg(long)]
+    pub check: bool,
+
+    /// Slowdown in percent that --check tolerates
+    #[arg(long, default_value_t = 10.0, requires = "check")]
+    pub threshold: f64,
+
+    /// Baseline file [default: baseline.toml in the workspace root]
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Passed on to criterion with --run, e.g. a filter like `day-08`
+    #[arg(last = true)]
+    pub criterion_args: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct DownloadArgs {
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// Fetch these days (repeatable)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Vec<u8>,
+
+    /// Fetch every day with a crate
+    #[arg(short, long, conflicts_with = "day")]
+    pub all: bool,
+
+    /// Fetch and overwrite inputs that are already present
+    #[arg(long)]
+    pub force: bool,
+
+    /// Session cookie [default: $AOC_SESSION]
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Server to talk to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// The answer to submit [default: the answer of the solution]
+    pub answer: Option<String>,
+
+    /// Sleep and try again when answering too soon after the previous answer,
+    /// and after a wrong answer sleep until the next one is accepted
+    #[arg(long)]
+    pub wait: bool,
+
+    /// Session cookie [default: $AOC_SESSION]
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Server to talk to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct DescribeArgs {
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Read the page from this HTML file instead of fetching it
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+
+    /// Session cookie, part two is only shown when logged in [default: $AOC_SESSION]
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Server to talk to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct LeaderboardArgs {
+    /// The event of the leaderboard to fetch
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// Fetch the private leaderboard with this id, the number in its URL
+    #[arg(long, required_unless_present = "file", conflicts_with = "file")]
+    pub id: Option<u64>,
+
+    /// Read the leaderboard JSON from this file instead
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t = Scoring::Local)]
+    pub scoring: Scoring,
+
+    /// Print Markdown tables instead
+    #[arg(long)]
+    pub markdown: bool,
+
+    /// Session cookie [default: $AOC_SESSION]
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Server to talk to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ScaffoldArgs {
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// Start part two from a copy of the part one crate
+    #[arg(long)]
+    pub from_part_one: bool,
+}
+
+pub fn run(cli: &Cli) -> Result<(), String> {
+    match &cli.command {
+        Some(Command::Bench(args)) => return bench(args),
+        Some(Command::Download(args)) => return download(args),
+        Some(Command::Submit(args)) => return submit(args),
+        Some(Command::Describe(args)) => return describe(args),
+        Some(Command::Leaderboard(args)) => return leaderboard(args),
+        Some(Command::Scaffold(args)) => return scaffold(args),
+        None => {}
+    }
+
+    if !cli.all && cli.year.is_empty() && cli.day.is_empty() && cli.part.is_empty() {
+        return Err("nothing selected, pass --year, --day, --part or --all".to_string());
+    }
+
+    let solvers: Vec<&Solver> = registry::select(&cli.year, &cli.day, &cli.part).collect();
+    if solvers.is_empty() {
+        return Err("no solution matches the selection".to_string());
+    }
+
+    if cli.input.is_some() && solvers.len() != 1 {
+        return Err(format!(
+            "--input needs a single solution, but {} are selected",
+            solvers.len()
+        ));
+    }
+
+    if cli.check {
+        return check(&solvers);
+    }
+
+    if let Some(rounds) = cli.repeat {
+        return repeat(cli, &solvers, rounds as usize);
+    }
+
+    let mut sources = Vec::with_capacity(solvers.len());
+    let mut rows = Vec::with_capacity(solvers.len());
+    #[cfg(feature = "trace")]
+    let mut breakdowns = Vec::with_capacity(solvers.len());
+    #[cfg(feature = "trace")]
+    trace::collector().take();
+
+    for solver in &solvers {
+        let source = solver.source(cli.input.clone());
+        let input = source.read().map_err(|e| format!("{}: {e}", solver.name))?;
+
+        let outcome = (solver.run)(&input).map_err(|e| {
+            format!(
+                "{}: {e}\n{}",
+                solver.name,
+                diagnostic::render(&source, &input, &e)
+            )
+        })?;
+
+        #[cfg(feature = "trace")]
+        breakdowns.push(trace::render(solver.name, &trace::collector().take()));
+
+        rows.push(Row {
+            year: solver.year,
+            day: solver.day,
+            part: solver.part,
+            answer: outcome.answer,
+            parse: outcome.parse,
+            solve: outcome.solve,
+            allocations: outcome.allocations,
+        });
+        sources.push(source);
+    }
+
+    print!("{}", table::render(&rows));
+
+    #[cfg(feature = "trace")]
+    for breakdown in &breakdowns {
+        println!();
+        print!("{breakdown}");
+    }
+
+    if cli.record {
+        record(&solvers, &sources, &rows)?;
+    }
+
+    Ok(())
+}
+
+fn repeat(cli: &Cli, solvers: &[&'static Solver], rounds: usize) -> Result<(), String> {
+    let jobs = solvers
+        .iter()
+        .map(|&solver| {
+            let source = solver.source(cli.input.clone());
+            let input = source.read().map_err(|e| format!("{}: {e}", solver.name))?;
+            Ok(Job {
+                solver,
+                source,
+                input,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let summary = repeat::repeat(&jobs, rounds, cli.concurrent)?;
+    print!("{}", repeat::render(&summary));
+
+    Ok(())
+}
+
+fn manifest_path() -> PathBuf {
+    workspace_root().join("answers.toml")
+}
+
+fn check(solvers: &[&Solver]) -> Result<(), String> {
+    let manifest = Manifest::load(&manifest_path())?;
+    let mismatches = manifest::verify(&manifest, &workspace_root(), solvers.iter().copied());
+
+    if mismatches.is_empty() {
+        println!("all {} answers match", solvers.len());
+        return Ok(());
+    }
+
+    for mismatch in &mismatches {
+        eprintln!("{mismatch}");
+    }
+
+    Err(format!("{} answers do not match", mismatches.len()))
+}
+
+fn record(solvers: &[&Solver], sources: &[Source], rows: &[Row]) -> Result<(), String> {
+    let path = manifest_path();
+    let mut manifest = if path.exists() {
+        Manifest::load(&path)?
+    } else {
+        Manifest::new()
+    };
+
+    let root = workspace_root();
+    for ((solver, source), row) in solvers.iter().zip(sources).zip(rows) {
+        let Source::File(path) = source else {
+            return Err(format!(
+                "{}: cannot record an answer for stdin",
+                solver.name
+            ));
+        };
+        let input = path.strip_prefix(&root).unwrap_or(path).to_path_buf();
+
+        manifest.record(solver.year, solver.day, solver.part, input, &row.answer);
+    }
+
+    manifest.save(&path)
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    if args.run {
+        let status = process::Command::new(env!("CARGO"))
+            .current_dir(workspace_root())
+            .args(["bench", "--workspace", "--bench", "solve_benchmark", "--"])
+            .args(&args.criterion_args)
+            .status()
+            .map_err(|e| format!("cannot run cargo bench: {e}"))?;
+        if !status.success() {
+            return Err(format!("cargo bench failed with {status}"));
+        }
+    }
+
+    let dir = report::criterion_dir();
+    let report = Report::collect(&dir)?;
+
+    let json = args.json.clone().unwrap_or_else(|| dir.join("report.json"));
+    fs::write(&json, report.to_json())
+        .map_err(|e| format!("cannot write {}: {e}", json.display()))?;
+
+    match &args.markdown {
+        Some(path) => fs::write(path, report.to_markdown())
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))?,
+        None => print!("{}", report.to_markdown()),
+    }
+
+    let path = args
+        .baseline
+        .clone()
+        .unwrap_or_else(|| workspace_root().join("baseline.toml"));
+    let current = Baseline::from_report(&report);
+
+    if args.save_baseline {
+        current.save(&path)?;
+    }
+
+    if args.check {
+        let deltas = Baseline::load(&path)?.compare(&current);
+        let (mut regressions, mut missing) = (0, 0);
+        for delta in &deltas {
+            if delta.regressed(args.threshold) {
+                regressions += 1;
+                println!("{delta}  <- regression");
+            } else if delta.missing() {
+                missing += 1;
+                println!("{delta}  <- not benchmarked");
+            } else {
+                println!("{delta}");
+            }
+        }
+
+        if missing > 0 {
+            return Err(format!(
+                "{missing} solutions in {} are missing from this run",
+                path.display()
+            ));
+        }
+        if regressions > 0 {
+            return Err(format!(
+                "{regressions} solutions are more than {}% slower than {}",
+                args.threshold,
+                path.display()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn client(session: Option<&String>, base_url: Option<&String>) -> Result<Client, String> {
+    let session = session
+        .cloned()
+        .or_else(|| env::var(client::SESSION_VAR).ok())
+        .ok_or_else(|| {
+            format!(
+                "no session cookie, pass --session or set {}",
+                client::SESSION_VAR
+            )
+        })?;
+
+    Ok(Client::new(&resolve_base_url(base_url), &session))
+}
+
+fn resolve_base_url(arg: Option<&String>) -> String {
+    arg.cloned()
+        .or_else(|| env::var(client::BASE_URL_VAR).ok())
+        .unwrap_or_else(|| client::DEFAULT_BASE_URL.to_string())
+}
+
+fn download(args: &DownloadArgs) -> Result<(), String> {
+    let mut days = if args.all {
+        registry::select(&[args.year], &[], &[])
+            .map(|solver| solver.day)
+            .collect()
+    } else {
+        args.day.clone()
+    };
+    days.sort_unstable();
+    days.dedup();
+    if days.is_empty() {
+        return Err("nothing selected, pass --day or --all".to_string());
+    }
+
+    let client = client(args.session.as_ref(), args.base_url.as_ref())?;
+    let root = workspace_root();
+    for day in days {
+        let download = client::download(&client, &root, args.year, day, args.force)?;
+        let from = if download.fetched {
+            "fetched"
+        } else {
+            "cached"
+        };
+        for path in &download.written {
+            println!("{} ({from})", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let solver = registry::select(&[args.year], &[args.day], &[args.part])
+        .next()
+        .ok_or_else(|| {
+            format!(
+                "there is no solution for {} day {} part {}",
+                args.year, args.day, args.part
+            )
+        })?;
+
+    let source = solver.source(None);
+    let Source::File(path) = &source else {
+        unreachable!("without an argument the input is always a file")
+    };
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let input = source.read().map_err(|e| format!("{}: {e}", solver.name))?;
+            (solver.run)(&input)
+                .map_err(|e| format!("{}: {e}", solver.name))?
+                .answer
+        }
+    };
+
+    let client = client(args.session.as_ref(), args.base_url.as_ref())?;
+    let manifest_path = manifest_path();
+    let mut manifest = if manifest_path.exists() {
+        Manifest::load(&manifest_path)?
+    } else {
+        Manifest::new()
+    };
+    let input = path.strip_prefix(workspace_root()).unwrap_or(path);
+
+    let verdict = loop {
+        match client::submit(&client, &mut manifest, solver, input, &answer)? {
+            Verdict::Wait(left) if args.wait => {
+                eprintln!("answered too recently, retrying in {left:?}");
+                thread::sleep(left);
+            }
+            verdict => break verdict,
+        }
+    };
+
+    println!(
+        "day {} part {}: {answer}: {verdict}",
+        solver.day, solver.part
+    );
+    if let Verdict::Wrong {
+        wait: Some(left), ..
+    } = verdict
+        && args.wait
+    {
+        eprintln!("waiting {left:?} until the next answer is accepted");
+        thread::sleep(left);
+    }
+    match verdict {
+        Verdict::Correct => manifest.save(&manifest_path),
+        _ => Err(format!("{answer} was not accepted")),
+    }
+}
+
+fn describe(args: &DescribeArgs) -> Result<(), String> {
+    let page = match &args.file {
+        Some(path) => {
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?
+        }
+        None => {
+            let session = args
+                .session
+                .clone()
+                .or_else(|| env::var(client::SESSION_VAR).ok());
+            let base_url = resolve_base_url(args.base_url.as_ref());
+            Client::new(&base_url, session.as_deref().unwrap_or_default())
+                .page(args.year, args.day)?
+        }
+    };
+
+    let parts = description::parse(&page);
+    if parts.is_empty() {
+        return Err("the page has no puzzle description".to_string());
+    }
+
+    let written = description::write(args.year, args.day, &parts)
+        .map_err(|e| format!("cannot write the description: {e}"))?;
+    for path in written {
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
+
+fn leaderboard(args: &LeaderboardArgs) -> Result<(), String> {
+    let json = match (&args.file, args.id) {
+        (Some(path), _) => {
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?
+        }
+        (None, Some(id)) => {
+            client(args.session.as_ref(), args.base_url.as_ref())?.leaderboard(args.year, id)?
+        }
+        (None, None) => unreachable!("clap requires --id or --file"),
+    };
+
+    let leaderboard = Leaderboard::parse(&json)?;
+    print!(
+        "{}",
+        leaderboard::render_ranking(&leaderboard.rank(args.scoring), args.markdown)
+    );
+    println!();
+    print!(
+        "{}",
+        leaderboard::render_timelines(&leaderboard.timelines()?, args.markdown)
+    );
+
+    Ok(())
+}
+
+fn scaffold(args: &ScaffoldArgs) -> Result<(), String> {
+    let written = scaffold::scaffold(
+        &workspace_root(),
+        args.year,
+        args.day,
+        args.part,
+        args.from_part_one,
+    )?;
+    for path in written {
+        println!("{}", path.display());
+    }
+
+    let selection = format!("--year {} --day {}", args.year, args.day);
+    println!();
+    println!("rebuild the runner, then fetch the input with `aoc download {selection}`");
+    println!(
+        "and store the answer with `aoc {selection} --part {} --record` once it is solved",
+        args.part
+    );
+
+    Ok(())
+}
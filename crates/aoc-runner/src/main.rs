@@ -0,0 +1,19 @@
+use aoc_runner::Cli;
+use clap::Parser;
+
+#[cfg(not(feature = "count-alloc"))]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: aoc_core::alloc::Counting = aoc_core::alloc::Counting;
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(e) = aoc_runner::run(&cli) {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
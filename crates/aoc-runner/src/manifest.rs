@@ -0,0 +1,331 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    hash::{BuildHasher, RandomState},
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{DEFAULT_YEAR, ParseError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::registry::Solver;
+
+const HEADER: &str =
+    "# Written by `aoc --record`. Answers are stored as sha256(salt, year, day, part, answer).\n\n";
+
+/// Known answers, salted and hashed so the file can be committed publicly.
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Manifest {
+    pub salt: String,
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<Entry>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Entry {
+    /// Left out for [`DEFAULT_YEAR`], which is all the manifest held before
+    /// other years were added.
+    #[serde(default = "default_year", skip_serializing_if = "is_default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Relative to the workspace root.
+    pub input: PathBuf,
+    pub sha256: String,
+}
+
+pub(crate) fn default_year() -> u16 {
+    DEFAULT_YEAR
+}
+
+pub(crate) fn is_default_year(year: &u16) -> bool {
+    *year == DEFAULT_YEAR
+}
+
+impl Manifest {
+    pub fn new() -> Self {
+        let salt = (0..2)
+            .map(|_| format!("{:016x}", RandomState::new().hash_one(0)))
+            .collect();
+
+        Self {
+            salt,
+            answers: Vec::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, format!("{HEADER}{text}"))
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+
+    pub fn hash(&self, year: u16, day: u8, part: u8, answer: &str) -> String {
+        Sha256::new()
+            .chain_update(&self.salt)
+            .chain_update(year.to_le_bytes())
+            .chain_update([day, part])
+            .chain_update(answer)
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    pub fn entries(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Entry> {
+        self.answers
+            .iter()
+            .filter(move |entry| (entry.year, entry.day, entry.part) == (year, day, part))
+    }
+
+    /// Adds or replaces the answer for this year, day, part and input.
+    pub fn record(&mut self, year: u16, day: u8, part: u8, input: PathBuf, answer: &str) {
+        let sha256 = self.hash(year, day, part, answer);
+
+        match self.answers.iter_mut().find(|entry| {
+            (entry.year, entry.day, entry.part, &entry.input) == (year, day, part, &input)
+        }) {
+            Some(entry) => entry.sha256 = sha256,
+            None => {
+                self.answers.push(Entry {
+                    year,
+                    day,
+                    part,
+                    input,
+                    sha256,
+                });
+                self.answers.sort_by(|a, b| {
+                    (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+                });
+            }
+        }
+    }
+}
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub enum Problem {
+    Missing,
+    Unreadable(String),
+    Parse(ParseError),
+    Wrong { answer: String },
+}
+
+#[derive(Debug)]
+pub struct Mismatch {
+    pub solver: &'static str,
+    pub input: PathBuf,
+    pub problem: Problem,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): ", self.solver, self.input.display())?;
+
+        match &self.problem {
+            Problem::Missing => write!(f, "no answer in the manifest"),
+            Problem::Unreadable(e) => write!(f, "cannot read input: {e}"),
+            Problem::Parse(e) => write!(f, "{e}"),
+            Problem::Wrong { answer } => write!(f, "answer {answer} does not match"),
+        }
+    }
+}
+
+/// Runs every solver against each of its manifest entries.
+pub fn verify<'a>(
+    manifest: &Manifest,
+    root: &Path,
+    solvers: impl IntoIterator<Item = &'a Solver>,
+) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    for solver in solvers {
+        let mut entries = manifest
+            .entries(solver.year, solver.day, solver.part)
+            .peekable();
+
+        if entries.peek().is_none() {
+            mismatches.push(Mismatch {
+                solver: solver.name,
+                input: solver.input(),
+                problem: Problem::Missing,
+            });
+        }
+
+        for entry in entries {
+            let problem = match fs::read_to_string(root.join(&entry.input)) {
+                Err(e) => Some(Problem::Unreadable(e.to_string())),
+                Ok(input) => match (solver.run)(&input) {
+                    Err(e) => Some(Problem::Parse(e)),
+                    Ok(outcome) => {
+                        (manifest.hash(solver.year, solver.day, solver.part, &outcome.answer)
+                            != entry.sha256)
+                            .then_some(Problem::Wrong {
+                                answer: outcome.answer,
+                            })
+                    }
+                },
+            };
+
+            if let Some(problem) = problem {
+                mismatches.push(Mismatch {
+                    solver: solver.name,
+                    input: entry.input.clone(),
+                    problem,
+                });
+            }
+        }
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc_core::ParseError;
+    use rstest::rstest;
+
+    use super::{Manifest, Problem, verify};
+    use crate::registry::{Outcome, Solver};
+
+    fn manifest() -> Manifest {
+        Manifest {
+            salt: "zout".to_string(),
+            answers: Vec::new(),
+        }
+    }
+
+    fn length(input: &str) -> Result<Outcome, ParseError> {
+        Ok(Outcome {
+            answer: input.len().to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+            allocations: None,
+        })
+    }
+
+    const SOLVER: Solver = Solver {
+        year: 2025,
+        day: 1,
+        part: 1,
+        name: "day-01-part-01",
+        parallel: false,
+        examples: true,
+        run: length,
+    };
+
+    #[rstest]
+    #[case("pepper", 2025, 1, 1, "42")]
+    #[case("zout", 2024, 1, 1, "42")]
+    #[case("zout", 2025, 2, 1, "42")]
+    #[case("zout", 2025, 1, 2, "42")]
+    #[case("zout", 2025, 1, 1, "43")]
+    fn hash_depends_on_salt_year_day_part_and_answer(
+        #[case] salt: &str,
+        #[case] year: u16,
+        #[case] day: u8,
+        #[case] part: u8,
+        #[case] answer: &str,
+    ) {
+        let other = Manifest {
+            salt: salt.to_string(),
+            answers: Vec::new(),
+        };
+
+        assert_eq!(
+            manifest().hash(2025, 1, 1, "42"),
+            manifest().hash(2025, 1, 1, "42")
+        );
+        assert_ne!(
+            manifest().hash(2025, 1, 1, "42"),
+            other.hash(year, day, part, answer)
+        );
+    }
+
+    #[test]
+    fn record_replaces_existing_entry_and_keeps_order() {
+        let mut manifest = manifest();
+        manifest.record(2025, 2, 1, PathBuf::from("b.txt"), "1");
+        manifest.record(2025, 1, 2, PathBuf::from("a.txt"), "1");
+        manifest.record(2024, 2, 1, PathBuf::from("c.txt"), "1");
+        manifest.record(2025, 2, 1, PathBuf::from("b.txt"), "2");
+
+        let keys: Vec<_> = manifest
+            .answers
+            .iter()
+            .map(|e| (e.year, e.day, e.part))
+            .collect();
+        assert_eq!(keys, [(2024, 2, 1), (2025, 1, 2), (2025, 2, 1)]);
+        assert_eq!(manifest.answers[2].sha256, manifest.hash(2025, 2, 1, "2"));
+        assert_eq!(manifest.entries(2024, 2, 1).count(), 1);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut manifest = manifest();
+        manifest.record(
+            2025,
+            1,
+            1,
+            PathBuf::from("puzzles/day-01-part-01/input.txt"),
+            "3",
+        );
+        manifest.record(
+            2024,
+            1,
+            1,
+            PathBuf::from("puzzles/2024/day-01-part-01/input.txt"),
+            "3",
+        );
+
+        let text = toml::to_string(&manifest).unwrap();
+        assert!(text.contains("[[answer]]"));
+        assert_eq!(text.matches("year = 2024").count(), 1);
+        assert!(!text.contains("year = 2025"));
+        assert_eq!(toml::from_str::<Manifest>(&text).unwrap(), manifest);
+    }
+
+    #[test]
+    fn verify_reports_every_kind_of_mismatch() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let cargo_toml = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+
+        let mut manifest = manifest();
+        assert!(matches!(
+            verify(&manifest, &root, [&SOLVER])[0].problem,
+            Problem::Missing
+        ));
+
+        manifest.record(
+            2025,
+            1,
+            1,
+            PathBuf::from("Cargo.toml"),
+            &cargo_toml.len().to_string(),
+        );
+        assert!(verify(&manifest, &root, [&SOLVER]).is_empty());
+
+        manifest.record(2025, 1, 1, PathBuf::from("Cargo.toml"), "0");
+        manifest.record(2025, 1, 1, PathBuf::from("missing.txt"), "0");
+        let problems: Vec<_> = verify(&manifest, &root, [&SOLVER])
+            .into_iter()
+            .map(|m| m.problem)
+            .collect();
+        assert!(matches!(problems[0], Problem::Wrong { .. }));
+        assert!(matches!(problems[1], Problem::Unreadable(_)));
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    ParseError, Solution,
+    alloc::{self, Allocations},
+    input::{INPUT_DIR_VAR, Source, puzzle_dir},
+};
+
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: &'static str,
+    /// Uses the rayon pool itself, so it should not share it with others.
+    pub parallel: bool,
+    /// Can solve the examples of its description, see [`Solution::EXAMPLES`].
+    pub examples: bool,
+    pub run: fn(&str) -> Result<Outcome, ParseError>,
+}
+
+pub struct Outcome {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Of parse and solve together, when `aoc` counts allocations. Not of
+    /// this solver alone when others run at the same time, see
+    /// [`alloc::measure`].
+    pub allocations: Option<Allocations>,
+}
+
+impl Solver {
+    const fn new<S: Solution>(name: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part: S::PART,
+            name,
+            parallel: S::PARALLEL,
+            examples: S::EXAMPLES,
+            run: run::<S>,
+        }
+    }
+
+    /// The crate directory, relative to the workspace root.
+    pub fn dir(&self) -> PathBuf {
+        Path::new("puzzles").join(puzzle_dir(self.name))
+    }
+
+    /// Relative to the workspace root.
+    pub fn input(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    /// Where to read the input from, see [`Source::resolve`].
+    pub fn source(&self, arg: Option<OsString>) -> Source {
+        Source::resolve(
+            arg,
+            env::var_os(INPUT_DIR_VAR),
+            self.name,
+            &workspace_root().join(self.dir()),
+        )
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn run<S: Solution>(input: &str) -> Result<Outcome, ParseError> {
+    let (timed, allocations) = alloc::measure(|| {
+        let timer = Instant::now();
+        let parsed = {
+            aoc_core::phase!("parse");
+            S::parse(input)?
+        };
+        let parse = timer.elapsed();
+
+        let timer = Instant::now();
+        let answer = {
+            aoc_core::phase!("solve");
+            S::solve(parsed)
+        };
+        let solve = timer.elapsed();
+
+        Ok::<_, ParseError>((answer, parse, solve))
+    });
+    let (answer, parse, solve) = timed?;
+
+    Ok(Outcome {
+        answer: answer.to_string(),
+        parse,
+        solve,
+        allocations: alloc::installed().then_some(allocations),
+    })
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day_01_part_01::Puzzle>("day-01-part-01"),
+    Solver::new::<day_01_part_02::Puzzle>("day-01-part-02"),
+    Solver::new::<day_02_part_01::Puzzle>("day-02-part-01"),
+    Solver::new::<day_02_part_02::Puzzle>("day-02-part-02"),
+    Solver::new::<day_03_part_01::Puzzle>("day-03-part-01"),
+    Solver::new::<day_03_part_02::Puzzle>("day-03-part-02"),
+    Solver::new::<day_04_part_01::Puzzle>("day-04-part-01"),
+    Solver::new::<day_04_part_02::Puzzle>("day-04-part-02"),
+    Solver::new::<day_05_part_01::Puzzle>("day-05-part-01"),
+    Solver::new::<day_05_part_02::Puzzle>("day-05-part-02"),
+    Solver::new::<day_06_part_01::Puzzle>("day-06-part-01"),
+    Solver::new::<day_06_part_02::Puzzle>("day-06-part-02"),
+    Solver::new::<day_07_part_01::Puzzle>("day-07-part-01"),
+    Solver::new::<day_07_part_02::Puzzle>("day-07-part-02"),
+    Solver::new::<day_08_part_01::Puzzle>("day-08-part-01"),
+    Solver::new::<day_08_part_02::Puzzle>("day-08-part-02"),
+    Solver::new::<day_09_part_01::Puzzle>("day-09-part-01"),
+    Solver::new::<day_09_part_02::Puzzle>("day-09-part-02"),
+    Solver::new::<day_10_part_01::Puzzle>("day-10-part-01"),
+    Solver::new::<day_10_part_02::Puzzle>("day-10-part-02"),
+    Solver::new::<day_11_part_01::Puzzle>("day-11-part-01"),
+    Solver::new::<day_11_part_02::Puzzle>("day-11-part-02"),
+    Solver::new::<day_12_part_01::Puzzle>("day-12-part-01"),
+];
+
+/// Lege filters betekenen "alles".
+pub fn select<'a>(
+    years: &'a [u16],
+    days: &'a [u8],
+    parts: &'a [u8],
+) -> impl Iterator<Item = &'static Solver> + 'a {
+    select_from(SOLVERS, years, days, parts)
+}
+
+fn select_from<'s: 'a, 'a>(
+    solvers: &'s [Solver],
+    years: &'a [u16],
+    days: &'a [u8],
+    parts: &'a [u8],
+) -> impl Iterator<Item = &'s Solver> + 'a {
+    solvers.iter().filter(move |solver| {
+        (years.is_empty() || years.contains(&solver.year))
+            && (days.is_empty() || days.contains(&solver.day))
+            && (parts.is_empty() || parts.contains(&solver.part))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use aoc_core::ParseError;
+    use rstest::rstest;
+
+    use super::{Outcome, SOLVERS, Solver, select_from, workspace_root};
+    use crate::scaffold::crate_name;
+
+    /// The puzzle crates in the workspace, without shared ones like `day-01`.
+    fn puzzle_members() -> Vec<PathBuf> {
+        let manifest = fs::read_to_string(workspace_root().join("Cargo.toml")).unwrap();
+        let manifest: toml::Table = toml::from_str(&manifest).unwrap();
+
+        manifest["workspace"]["members"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|member| PathBuf::from(member.as_str().unwrap()))
+            .filter(|member| member.starts_with("puzzles"))
+            .filter(|member| member.to_string_lossy().contains("-part-"))
+            .collect()
+    }
+
+    #[test]
+    fn every_day_is_registered_once() {
+        let members = puzzle_members();
+        assert_eq!(SOLVERS.len(), members.len());
+
+        for (i, a) in SOLVERS.iter().enumerate() {
+            assert_eq!(a.name, crate_name(a.year, a.day, a.part));
+            assert!(members.contains(&a.dir()), "{} is no member", a.name);
+            assert!(
+                SOLVERS[i + 1..]
+                    .iter()
+                    .all(|b| (a.year, a.day, a.part) != (b.year, b.day, b.part))
+            );
+        }
+    }
+
+    #[test]
+    fn parallel_solvers_are_the_ones_on_rayon() {
+        let parallel: Vec<_> = SOLVERS
+            .iter()
+            .filter(|solver| solver.parallel)
+            .map(|solver| solver.name)
+            .collect();
+
+        assert_eq!(parallel, ["day-02-part-02", "day-10-part-02"]);
+    }
+
+    fn unused(_: &str) -> Result<Outcome, ParseError> {
+        unreachable!()
+    }
+
+    /// The calendar of 2025: two parts a day, one on the last.
+    fn calendar() -> Vec<Solver> {
+        (1..=12)
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .filter(|&(day, part)| (day, part) != (12, 2))
+            .map(|(day, part)| Solver {
+                year: 2025,
+                day,
+                part,
+                name: "test",
+                parallel: false,
+                examples: true,
+                run: unused,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[], &[], &[], 23)]
+    #[case(&[], &[1], &[], 2)]
+    #[case(&[], &[12], &[], 1)]
+    #[case(&[], &[], &[2], 11)]
+    #[case(&[], &[3, 4], &[1], 2)]
+    #[case(&[], &[12], &[2], 0)]
+    #[case(&[2025], &[1], &[], 2)]
+    #[case(&[2024], &[], &[], 0)]
+    fn select_filters_by_year_day_and_part(
+        #[case] years: &[u16],
+        #[case] days: &[u8],
+        #[case] parts: &[u8],
+        #[case] expected: usize,
+    ) {
+        let calendar = calendar();
+
+        assert_eq!(select_from(&calendar, years, days, parts).count(), expected);
+    }
+}
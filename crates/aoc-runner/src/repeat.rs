@@ -0,0 +1,323 @@
+use std::time::{Duration, Instant};
+
+use aoc_core::{DEFAULT_YEAR, input::Source};
+use rayon::prelude::*;
+
+use crate::{
+    diagnostic,
+    registry::{Outcome, Solver},
+    table,
+};
+
+/// A solver with its input, read once for every round.
+pub struct Job {
+    pub solver: &'static Solver,
+    pub source: Source,
+    pub input: String,
+}
+
+impl Job {
+    fn run(&self) -> Result<Outcome, String> {
+        (self.solver.run)(&self.input).map_err(|e| {
+            format!(
+                "{}: {e}\n{}",
+                self.solver.name,
+                diagnostic::render(&self.source, &self.input, &e)
+            )
+        })
+    }
+}
+
+/// The parse and solve time of one solver in every round.
+pub struct Timings {
+    pub solver: &'static Solver,
+    pub answer: String,
+    pub samples: Vec<Duration>,
+}
+
+pub struct Summary {
+    pub timings: Vec<Timings>,
+    /// How long every round took as a whole.
+    pub rounds: Vec<Duration>,
+    pub concurrent: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Nearest-rank percentiles, so the median of an even number of samples
+    /// is the lower of the middle two. `samples` must not be empty.
+    pub fn of(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let rank = |percent: usize| sorted[(sorted.len() * percent).div_ceil(100) - 1];
+
+        Stats {
+            min: sorted[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+/// Runs every job `rounds` times. With `concurrent` the jobs share the rayon
+/// pool, except those that are [`Solver::parallel`] themselves: they run one
+/// at a time once the others are done, so they get the pool to themselves.
+pub fn repeat(jobs: &[Job], rounds: usize, concurrent: bool) -> Result<Summary, String> {
+    let mut timings: Vec<Timings> = Vec::with_capacity(jobs.len());
+    let mut walls = Vec::with_capacity(rounds);
+
+    for _ in 0..rounds {
+        let timer = Instant::now();
+        let outcomes = round(jobs, concurrent)?;
+        walls.push(timer.elapsed());
+
+        if timings.is_empty() {
+            timings = jobs
+                .iter()
+                .zip(&outcomes)
+                .map(|(job, outcome)| Timings {
+                    solver: job.solver,
+                    answer: outcome.answer.clone(),
+                    samples: Vec::with_capacity(rounds),
+                })
+                .collect();
+        }
+
+        for (timing, outcome) in timings.iter_mut().zip(outcomes) {
+            if outcome.answer != timing.answer {
+                return Err(format!(
+                    "{}: answered {} and later {}",
+                    timing.solver.name, timing.answer, outcome.answer
+                ));
+            }
+            timing.samples.push(outcome.parse + outcome.solve);
+        }
+    }
+
+    Ok(Summary {
+        timings,
+        rounds: walls,
+        concurrent,
+    })
+}
+
+/// The outcomes in the order of `jobs`.
+fn round(jobs: &[Job], concurrent: bool) -> Result<Vec<Outcome>, String> {
+    if !concurrent {
+        return jobs.iter().map(Job::run).collect();
+    }
+
+    let shared = jobs
+        .par_iter()
+        .map(|job| (!job.solver.parallel).then(|| job.run()).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    shared
+        .into_iter()
+        .zip(jobs)
+        .map(|(outcome, job)| outcome.map_or_else(|| job.run(), Ok))
+        .collect()
+}
+
+/// The statistics of every solver and their sums, then those of the rounds.
+pub fn render(summary: &Summary) -> String {
+    let years = summary
+        .timings
+        .iter()
+        .any(|timing| timing.solver.year != DEFAULT_YEAR);
+    let skip = usize::from(!years);
+
+    let stats: Vec<Stats> = summary
+        .timings
+        .iter()
+        .map(|timing| Stats::of(&timing.samples))
+        .collect();
+    let cells: Vec<Vec<String>> = summary
+        .timings
+        .iter()
+        .zip(&stats)
+        .map(|(timing, stats)| {
+            vec![
+                timing.solver.year.to_string(),
+                timing.solver.day.to_string(),
+                timing.solver.part.to_string(),
+                timing.answer.clone(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+            ]
+            .split_off(skip)
+        })
+        .collect();
+
+    let sum = |f: fn(&Stats) -> Duration| stats.iter().map(f).sum::<Duration>();
+    let mut footer = vec![String::new(); 3 - skip];
+    footer[0] = "Total".to_string();
+    footer.extend([
+        String::new(),
+        format!("{:.2?}", sum(|stats| stats.min)),
+        format!("{:.2?}", sum(|stats| stats.median)),
+        format!("{:.2?}", sum(|stats| stats.p95)),
+    ]);
+
+    let mut out = table::grid(
+        &["Year", "Day", "Part", "Answer", "Min", "Median", "P95"][skip..],
+        &cells,
+        Some(&footer),
+    );
+
+    let rounds = Stats::of(&summary.rounds);
+    let schedule = if summary.concurrent {
+        "concurrently"
+    } else {
+        "one after another"
+    };
+    out.push_str(&format!(
+        "\n{} rounds {schedule}: min {:.2?}, median {:.2?}, p95 {:.2?}\n",
+        summary.rounds.len(),
+        rounds.min,
+        rounds.median,
+        rounds.p95
+    ));
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc_core::{ParseError, input::Source};
+    use indoc::indoc;
+    use rstest::rstest;
+
+    use super::{Job, Stats, Summary, Timings, render, repeat};
+    use crate::registry::{Outcome, Solver};
+
+    fn length(input: &str) -> Result<Outcome, ParseError> {
+        Ok(Outcome {
+            answer: input.len().to_string(),
+            parse: Duration::from_micros(1),
+            solve: Duration::from_micros(input.len() as u64),
+            allocations: None,
+        })
+    }
+
+    const fn solver(day: u8, parallel: bool) -> Solver {
+        Solver {
+            year: 2025,
+            day,
+            part: 1,
+            name: "test",
+            parallel,
+            examples: true,
+            run: length,
+        }
+    }
+
+    static SOLVERS: [Solver; 6] = [
+        solver(1, false),
+        solver(2, false),
+        solver(3, true),
+        solver(4, false),
+        solver(5, false),
+        solver(6, true),
+    ];
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[rstest]
+    #[case(&[7], 7, 7, 7)]
+    #[case(&[4, 1, 3, 2], 1, 2, 4)]
+    #[case(&[5, 1, 4, 2, 3], 1, 3, 5)]
+    #[case(&[2; 20], 2, 2, 2)]
+    fn stats_are_nearest_rank(
+        #[case] samples: &[u64],
+        #[case] min: u64,
+        #[case] median: u64,
+        #[case] p95: u64,
+    ) {
+        assert_eq!(
+            Stats::of(&millis(samples)),
+            Stats {
+                min: Duration::from_millis(min),
+                median: Duration::from_millis(median),
+                p95: Duration::from_millis(p95),
+            }
+        );
+    }
+
+    #[test]
+    fn p95_skips_the_slowest_of_many() {
+        let samples = millis(&(1..=100).collect::<Vec<_>>());
+
+        assert_eq!(Stats::of(&samples).p95, Duration::from_millis(95));
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn repeat_keeps_the_order_of_the_jobs(#[case] concurrent: bool) {
+        let jobs: Vec<Job> = SOLVERS
+            .iter()
+            .map(|solver| Job {
+                solver,
+                source: Source::File(PathBuf::from("input.txt")),
+                input: "x".repeat(solver.day.into()),
+            })
+            .collect();
+
+        let summary = repeat(&jobs, 3, concurrent).unwrap();
+
+        assert_eq!(summary.rounds.len(), 3);
+        for (timing, day) in summary.timings.iter().zip(1..) {
+            assert_eq!(timing.solver.day, day);
+            assert_eq!(timing.answer, day.to_string());
+            assert_eq!(
+                timing.samples,
+                [Duration::from_micros(1 + u64::from(day)); 3]
+            );
+        }
+    }
+
+    #[test]
+    fn renders_stats_and_rounds() {
+        let summary = Summary {
+            timings: vec![
+                Timings {
+                    solver: &SOLVERS[0],
+                    answer: "3".to_string(),
+                    samples: millis(&[3, 1, 2]),
+                },
+                Timings {
+                    solver: &SOLVERS[1],
+                    answer: "42".to_string(),
+                    samples: millis(&[10, 30, 20]),
+                },
+            ],
+            rounds: millis(&[13, 31, 22]),
+            concurrent: true,
+        };
+
+        let expected = indoc! {"
+              Day | Part | Answer |     Min |  Median |     P95
+            ------+------+--------+---------+---------+--------
+                1 |    1 |      3 |  1.00ms |  2.00ms |  3.00ms
+                2 |    1 |     42 | 10.00ms | 20.00ms | 30.00ms
+            ------+------+--------+---------+---------+--------
+            Total |      |        | 11.00ms | 22.00ms | 33.00ms
+
+            3 rounds concurrently: min 13.00ms, median 22.00ms, p95 31.00ms
+        "};
+
+        assert_eq!(render(&summary), expected);
+    }
+}
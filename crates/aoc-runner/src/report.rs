@@ -0,0 +1,330 @@
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_core::DEFAULT_YEAR;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    manifest::is_default_year,
+    registry::{SOLVERS, workspace_root},
+};
+
+/// Where criterion keeps its results, honouring `$CARGO_TARGET_DIR`.
+pub fn criterion_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| workspace_root().join("target"), PathBuf::from)
+        .join("criterion")
+}
+
+/// Criterion's `benchmark.json`.
+#[derive(Debug, Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+    value_str: Option<String>,
+    throughput: Option<Throughput>,
+}
+
+#[derive(Debug, Deserialize)]
+enum Throughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Elements(#[allow(dead_code)] u64),
+}
+
+/// Criterion's `estimates.json`, in nanoseconds.
+#[derive(Debug, Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+    std_dev: Estimate,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Measurement {
+    #[serde(skip_serializing_if = "is_default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `parse`, `solve` or `total`.
+    pub phase: String,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+    pub bytes_per_second: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Report {
+    pub benchmarks: Vec<Measurement>,
+    /// Sum of the mean `total` of every solution.
+    pub calendar_total_ns: f64,
+}
+
+impl Report {
+    /// Reads the latest result of every `solve_benchmark` under `dir`. Scaling
+    /// groups and benchmarks of unknown crates are skipped.
+    pub fn collect(dir: &Path) -> Result<Self, String> {
+        let mut found = Vec::new();
+        find_results(dir, &mut found).map_err(|e| format!("cannot read {}: {e}", dir.display()))?;
+
+        let mut benchmarks = Vec::new();
+        for result in found {
+            let read = |name: &str| {
+                let path = result.join(name);
+                fs::read_to_string(&path)
+                    .map_err(|e| format!("cannot read {}: {e}", path.display()))
+            };
+
+            let measurement = measurement(&read("benchmark.json")?, &read("estimates.json")?)
+                .map_err(|e| format!("{}: {e}", result.display()))?;
+            benchmarks.extend(measurement);
+        }
+
+        if benchmarks.is_empty() {
+            return Err(format!(
+                "no benchmark results in {}, run `cargo bench` or pass --run",
+                dir.display()
+            ));
+        }
+
+        Ok(Self::new(benchmarks))
+    }
+
+    fn new(mut benchmarks: Vec<Measurement>) -> Self {
+        benchmarks.sort_by(|a, b| {
+            (a.year, a.day, a.part, &a.phase).cmp(&(b.year, b.day, b.part, &b.phase))
+        });
+        let calendar_total_ns = benchmarks
+            .iter()
+            .filter(|m| m.phase == "total")
+            .map(|m| m.mean_ns)
+            .sum();
+
+        Self {
+            benchmarks,
+            calendar_total_ns,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report only holds plain numbers and strings")
+    }
+
+    /// One row per solution with its `total` benchmark, and the calendar total.
+    /// The year gets a column once there is more than [`DEFAULT_YEAR`].
+    pub fn to_markdown(&self) -> String {
+        let years = self.benchmarks.iter().any(|m| m.year != DEFAULT_YEAR);
+        let (header, rule, gap) = match years {
+            true => ("| Year ", "|-----:", " |"),
+            false => ("", "", ""),
+        };
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "{header}| Day | Part | Mean | Median | Std dev | Throughput |"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "{rule}|----:|-----:|-----:|-------:|--------:|-----------:|"
+        )
+        .unwrap();
+
+        for m in self.benchmarks.iter().filter(|m| m.phase == "total") {
+            if years {
+                write!(out, "| {} ", m.year).unwrap();
+            }
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                m.day,
+                m.part,
+                nanos(m.mean_ns),
+                nanos(m.median_ns),
+                nanos(m.std_dev_ns),
+                m.bytes_per_second.map_or_else(String::new, throughput),
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "| **Total** |{gap} | **{}** | | | |",
+            nanos(self.calendar_total_ns)
+        )
+        .unwrap();
+
+        out
+    }
+}
+
+/// Every `new` directory below `dir`, criterion writes the latest run there.
+fn find_results(dir: &Path, found: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        if path.file_name().is_some_and(|name| name == "new") {
+            if path.join("benchmark.json").is_file() {
+                found.push(path);
+            }
+        } else {
+            find_results(&path, found)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn measurement(benchmark: &str, estimates: &str) -> Result<Option<Measurement>, serde_json::Error> {
+    let id: BenchmarkId = serde_json::from_str(benchmark)?;
+    let estimates: Estimates = serde_json::from_str(estimates)?;
+
+    let Some(solver) = SOLVERS.iter().find(|solver| solver.name == id.group_id) else {
+        return Ok(None);
+    };
+    let (Some(phase), None) = (id.function_id, id.value_str) else {
+        return Ok(None);
+    };
+
+    let bytes = match id.throughput {
+        Some(Throughput::Bytes(bytes) | Throughput::BytesDecimal(bytes)) => Some(bytes),
+        Some(Throughput::Elements(_)) | None => None,
+    };
+
+    Ok(Some(Measurement {
+        year: solver.year,
+        day: solver.day,
+        part: solver.part,
+        phase,
+        mean_ns: estimates.mean.point_estimate,
+        median_ns: estimates.median.point_estimate,
+        std_dev_ns: estimates.std_dev.point_estimate,
+        bytes_per_second: bytes.map(|bytes| bytes as f64 / (estimates.mean.point_estimate / 1e9)),
+    }))
+}
+
+fn nanos(ns: f64) -> String {
+    format!("{:.2?}", Duration::from_secs_f64(ns / 1e9))
+}
+
+fn throughput(bytes_per_second: f64) -> String {
+    const UNITS: [&str; 4] = ["B/s", "KiB/s", "MiB/s", "GiB/s"];
+
+    let mut value = bytes_per_second;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{Measurement, Report, measurement};
+
+    fn estimates(mean: f64) -> String {
+        format!(
+            r#"{{"mean":{{"point_estimate":{mean}}},"median":{{"point_estimate":{}}},"std_dev":{{"point_estimate":{}}}}}"#,
+            mean - 100.0,
+            mean / 10.0
+        )
+    }
+
+    fn total(day: u8, part: u8, mean_ns: f64) -> Measurement {
+        Measurement {
+            year: 2025,
+            day,
+            part,
+            phase: "total".to_string(),
+            mean_ns,
+            median_ns: mean_ns,
+            std_dev_ns: 0.0,
+            bytes_per_second: Some(2048.0 * 1e9 / mean_ns),
+        }
+    }
+
+    #[test]
+    fn measurement_reads_criterion_files() {
+        let id = r#"{"group_id":"day-04-part-01","function_id":"total","value_str":null,"throughput":{"Bytes":18632},"full_id":"day-04-part-01/total"}"#;
+
+        let m = measurement(id, &estimates(1000.0)).unwrap().unwrap();
+
+        assert_eq!(
+            (m.year, m.day, m.part, m.phase.as_str()),
+            (2025, 4, 1, "total")
+        );
+        assert_eq!(
+            (m.mean_ns, m.median_ns, m.std_dev_ns),
+            (1000.0, 900.0, 100.0)
+        );
+        assert_eq!(m.bytes_per_second, Some(18632.0 * 1e6));
+    }
+
+    #[test]
+    fn measurement_skips_scaling_groups_and_unknown_crates() {
+        let scaling = r#"{"group_id":"day-04-part-01/scaling","function_id":"solve","value_str":"32","throughput":{"Bytes":1056}}"#;
+        let unknown =
+            r#"{"group_id":"aoc-core","function_id":"total","value_str":null,"throughput":null}"#;
+
+        assert_eq!(measurement(scaling, &estimates(1000.0)).unwrap(), None);
+        assert_eq!(measurement(unknown, &estimates(1000.0)).unwrap(), None);
+    }
+
+    #[test]
+    fn markdown_lists_totals_and_the_calendar_total() {
+        let mut parse = total(1, 2, 50_000.0);
+        parse.phase = "parse".to_string();
+        let report = Report::new(vec![
+            total(11, 2, 2_500_000.0),
+            parse,
+            total(1, 2, 1_500_000.0),
+        ]);
+
+        let expected = indoc! {"
+            | Day | Part | Mean | Median | Std dev | Throughput |
+            |----:|-----:|-----:|-------:|--------:|-----------:|
+            | 1 | 2 | 1.50ms | 1.50ms | 0.00ns | 1.30 MiB/s |
+            | 11 | 2 | 2.50ms | 2.50ms | 0.00ns | 800.00 KiB/s |
+            | **Total** | | **4.00ms** | | | |
+        "};
+        assert_eq!(report.to_markdown(), expected);
+        assert_eq!(report.calendar_total_ns, 4_000_000.0);
+    }
+
+    #[test]
+    fn markdown_shows_the_year_once_there_are_more() {
+        let mut older = total(11, 2, 2_500_000.0);
+        older.year = 2024;
+        let report = Report::new(vec![total(1, 2, 1_500_000.0), older]);
+
+        let expected = indoc! {"
+            | Year | Day | Part | Mean | Median | Std dev | Throughput |
+            |-----:|----:|-----:|-----:|-------:|--------:|-----------:|
+            | 2024 | 11 | 2 | 2.50ms | 2.50ms | 0.00ns | 800.00 KiB/s |
+            | 2025 | 1 | 2 | 1.50ms | 1.50ms | 0.00ns | 1.30 MiB/s |
+            | **Total** | | | **4.00ms** | | | |
+        "};
+        assert_eq!(report.to_markdown(), expected);
+        assert!(!report.to_json().contains("2025"));
+        assert!(report.to_json().contains(r#""year": 2024"#));
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{DEFAULT_YEAR, input::puzzle_dir};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const BENCH_RS: &str = include_str!("../templates/solve_benchmark.rs.tmpl");
+
+/// Crates of other years than [`DEFAULT_YEAR`] get the year as a prefix, so
+/// they do not collide in the workspace.
+pub fn crate_name(year: u16, day: u8, part: u8) -> String {
+    match year {
+        DEFAULT_YEAR => format!("day-{day:02}-part-{part:02}"),
+        _ => format!("y{year}-day-{day:02}-part-{part:02}"),
+    }
+}
+
+/// Creates the crate directory, see [`puzzle_dir`], under `root` and
+/// registers it in the workspace, the runner's dependencies and
+/// [`crate::registry::SOLVERS`]. With `from_part_one` the sources of part one
+/// are copied instead of the templates, renamed to part two. Returns the
+/// paths written, relative to `root`.
+pub fn scaffold(
+    root: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    from_part_one: bool,
+) -> Result<Vec<PathBuf>, String> {
+    if from_part_one && part != 2 {
+        return Err("only part two can start from part one".to_string());
+    }
+
+    let name = crate_name(year, day, part);
+    let relative = format!("puzzles/{}", puzzle_dir(&name));
+    let dir = root.join(&relative);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let files = if from_part_one {
+        let part_one = crate_name(year, day, 1);
+        copy_of(
+            &root.join("puzzles").join(puzzle_dir(&part_one)),
+            &part_one,
+            &name,
+        )?
+    } else {
+        templates(year, day, part)
+    };
+
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        let target = dir.join(&path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("cannot create {}: {e}", parent.display()))?;
+        }
+        fs::write(&target, contents)
+            .map_err(|e| format!("cannot write {}: {e}", target.display()))?;
+        written.push(Path::new(&relative).join(path));
+    }
+
+    let ident = name.replace('-', "_");
+    let registrations = [
+        (
+            "Cargo.toml",
+            "    \"puzzles/",
+            format!("    \"{relative}\","),
+        ),
+        (
+            "crates/aoc-runner/Cargo.toml",
+            "path = \"../../puzzles/",
+            format!("{name} = {{ path = \"../../{relative}\" }}"),
+        ),
+        (
+            "crates/aoc-runner/src/registry.rs",
+            "    Solver::new::<",
+            format!("    Solver::new::<{ident}::Puzzle>(\"{name}\"),"),
+        ),
+    ];
+    for (path, marker, line) in registrations {
+        let file = root.join(path);
+        let text = fs::read_to_string(&file)
+            .map_err(|e| format!("cannot read {}: {e}", file.display()))?;
+        let updated = insert_sorted(&text, marker, &line)
+            .ok_or_else(|| format!("{} has no line with {marker:?}", file.display()))?;
+        if updated != text {
+            fs::write(&file, updated)
+                .map_err(|e| format!("cannot write {}: {e}", file.display()))?;
+            written.push(PathBuf::from(path));
+        }
+    }
+
+    Ok(written)
+}
+
+fn templates(year: u16, day: u8, part: u8) -> Vec<(PathBuf, String)> {
+    let name = crate_name(year, day, part);
+    let fill = |template: &str| {
+        template
+            .replace("{name}", &name)
+            .replace("{ident}", &name.replace('-', "_"))
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{part}", &part.to_string())
+    };
+
+    vec![
+        ("Cargo.toml".into(), fill(CARGO_TOML)),
+        ("src/lib.rs".into(), fill(LIB_RS)),
+        ("src/main.rs".into(), fill(MAIN_RS)),
+        ("benches/solve_benchmark.rs".into(), fill(BENCH_RS)),
+        ("input.txt".into(), String::new()),
+    ]
+}
+
+/// The manifest, sources, benches and input of part one, renamed to part
+/// two. The description is left out, part two has its own.
+fn copy_of(part_one: &Path, from: &str, to: &str) -> Result<Vec<(PathBuf, String)>, String> {
+    if !part_one.is_dir() {
+        return Err(format!("{} does not exist", part_one.display()));
+    }
+
+    let renames = [
+        (from.to_string(), to.to_string()),
+        (from.replace('-', "_"), to.replace('-', "_")),
+        (
+            "const PART: u8 = 1;".to_string(),
+            "const PART: u8 = 2;".to_string(),
+        ),
+    ];
+
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        let entries = fs::read_dir(part_one.join(&relative))
+            .map_err(|e| format!("cannot read {}: {e}", part_one.display()))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("cannot read {}: {e}", part_one.display()))?;
+            let name = entry.file_name();
+            let path = relative.join(&name);
+            if path.starts_with("target") || name.to_string_lossy().starts_with("description") {
+                continue;
+            }
+            if entry.path().is_dir() {
+                pending.push(path);
+                continue;
+            }
+
+            let contents = fs::read_to_string(entry.path())
+                .map_err(|e| format!("cannot read {}: {e}", entry.path().display()))?;
+            let contents = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("rs" | "toml") => renames
+                    .iter()
+                    .fold(contents, |contents, (from, to)| contents.replace(from, to)),
+                _ => contents,
+            };
+            files.push((path, contents));
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Inserts `line` among the lines containing `marker`, which are kept sorted.
+/// `None` when there are no such lines to go by.
+fn insert_sorted(text: &str, marker: &str, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let group: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].contains(marker))
+        .collect();
+    let last = *group.last()?;
+
+    if group.iter().any(|&i| lines[i] == line) {
+        return Some(text.to_string());
+    }
+
+    let at = group
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > line)
+        .unwrap_or(last + 1);
+
+    let mut out: Vec<&str> = lines;
+    out.insert(at, line);
+    let mut out = out.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use indoc::indoc;
+    use rstest::rstest;
+
+    use super::{crate_name, insert_sorted, scaffold};
+
+    const MEMBERS: &str = indoc! {r#"
+        members = [
+            "crates/aoc-runner",
+            "puzzles/day-01-part-01",
+            "puzzles/day-03-part-01",
+        ]
+    "#};
+
+    #[rstest]
+    #[case("    \"puzzles/day-02-part-01\",", 3)]
+    #[case("    \"puzzles/day-04-part-01\",", 4)]
+    #[case("    \"puzzles/day-00-part-01\",", 2)]
+    fn insert_sorted_keeps_the_order(#[case] line: &str, #[case] at: usize) {
+        let updated = insert_sorted(MEMBERS, "    \"puzzles/", line).unwrap();
+
+        assert_eq!(updated.lines().nth(at), Some(line));
+        assert_eq!(updated.lines().count(), MEMBERS.lines().count() + 1);
+        assert!(updated.ends_with("]\n"));
+    }
+
+    #[test]
+    fn insert_sorted_is_idempotent() {
+        let line = "    \"puzzles/day-03-part-01\",";
+
+        assert_eq!(
+            insert_sorted(MEMBERS, "    \"puzzles/", line).as_deref(),
+            Some(MEMBERS)
+        );
+        assert_eq!(insert_sorted(MEMBERS, "Solver::", line), None);
+    }
+
+    #[rstest]
+    #[case(2025, 1, 2, "day-01-part-02")]
+    #[case(2024, 25, 1, "y2024-day-25-part-01")]
+    fn crate_name_prefixes_other_years(
+        #[case] year: u16,
+        #[case] day: u8,
+        #[case] part: u8,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(crate_name(year, day, part), expected);
+    }
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let runner = root.path().join("crates/aoc-runner");
+        fs::create_dir_all(runner.join("src")).unwrap();
+        fs::write(root.path().join("Cargo.toml"), MEMBERS).unwrap();
+        fs::write(
+            runner.join("Cargo.toml"),
+            "[dependencies]\nday-01-part-01 = { path = \"../../puzzles/day-01-part-01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            runner.join("src/registry.rs"),
+            "pub const SOLVERS: &[Solver] = &[\n    Solver::new::<day_01_part_01::Puzzle>(\"day-01-part-01\"),\n];\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn scaffold_creates_and_registers_the_crate() {
+        let root = workspace();
+
+        let written = scaffold(root.path(), 2025, 2, 1, false).unwrap();
+
+        assert_eq!(written.len(), 8);
+        let dir = root.path().join("puzzles/day-02-part-01");
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("const DAY: u8 = 2;"), "{lib}");
+        assert!(
+            fs::read_to_string(dir.join("src/main.rs"))
+                .unwrap()
+                .contains("day_02_part_01::solve(&input)")
+        );
+        assert!(
+            fs::read_to_string(root.path().join("crates/aoc-runner/src/registry.rs"))
+                .unwrap()
+                .contains("    Solver::new::<day_02_part_01::Puzzle>(\"day-02-part-01\"),\n];")
+        );
+
+        let error = scaffold(root.path(), 2025, 2, 1, false).unwrap_err();
+        assert!(error.ends_with("day-02-part-01 already exists"), "{error}");
+    }
+
+    #[test]
+    fn scaffold_nests_other_years() {
+        let root = workspace();
+
+        scaffold(root.path(), 2024, 5, 1, false).unwrap();
+
+        let lib = fs::read_to_string(root.path().join("puzzles/2024/day-05-part-01/src/lib.rs"));
+        assert!(lib.unwrap().contains("const YEAR: u16 = 2024;"));
+        assert_eq!(
+            fs::read_to_string(root.path().join("Cargo.toml")).unwrap(),
+            indoc! {r#"
+                members = [
+                    "crates/aoc-runner",
+                    "puzzles/2024/day-05-part-01",
+                    "puzzles/day-01-part-01",
+                    "puzzles/day-03-part-01",
+                ]
+            "#}
+        );
+        assert!(
+            fs::read_to_string(root.path().join("crates/aoc-runner/Cargo.toml"))
+                .unwrap()
+                .ends_with(
+                    "\ny2024-day-05-part-01 = { path = \"../../puzzles/2024/day-05-part-01\" }\n"
+                )
+        );
+    }
+
+    #[test]
+    fn scaffold_copies_part_one() {
+        let root = workspace();
+        let part_one = root.path().join("puzzles/day-01-part-01");
+        fs::create_dir_all(part_one.join("src")).unwrap();
+        fs::write(part_one.join("Cargo.toml"), "name = \"day-01-part-01\"\n").unwrap();
+        fs::write(
+            part_one.join("src/lib.rs"),
+            "const PART: u8 = 1;\nuse day_01_part_01::Puzzle;\n",
+        )
+        .unwrap();
+        fs::write(part_one.join("description.md"), "part one\n").unwrap();
+        fs::write(part_one.join("input.txt"), "L68\n").unwrap();
+
+        scaffold(root.path(), 2025, 1, 2, true).unwrap();
+
+        let part_two = root.path().join("puzzles/day-01-part-02");
+        assert_eq!(
+            fs::read_to_string(part_two.join("src/lib.rs")).unwrap(),
+            "const PART: u8 = 2;\nuse day_01_part_02::Puzzle;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(part_two.join("Cargo.toml")).unwrap(),
+            "name = \"day-01-part-02\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(part_two.join("input.txt")).unwrap(),
+            "L68\n"
+        );
+        assert!(!part_two.join("description.md").exists());
+        assert_eq!(
+            scaffold(root.path(), 2025, 1, 1, true),
+            Err("only part two can start from part one".to_string())
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::{fmt::Write, time::Duration};
+
+use aoc_core::{DEFAULT_YEAR, alloc::Allocations};
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+impl Row {
+    fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Only shows the year when a row is not of [`DEFAULT_YEAR`], and the
+/// allocations when they were counted.
+pub fn render(rows: &[Row]) -> String {
+    let years = rows.iter().any(|row| row.year != DEFAULT_YEAR);
+    let skip = usize::from(!years);
+    let counted = rows.iter().any(|row| row.allocations.is_some());
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut line = vec![
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.to_string(),
+                row.answer.clone(),
+                format!("{:.2?}", row.parse),
+                format!("{:.2?}", row.solve),
+                format!("{:.2?}", row.total()),
+            ]
+            .split_off(skip);
+            if counted {
+                let allocations = row.allocations.unwrap_or_default();
+                line.extend([
+                    allocations.count.to_string(),
+                    allocations.bytes.to_string(),
+                    allocations.peak.to_string(),
+                ]);
+            }
+            line
+        })
+        .collect();
+    let parse: Duration = rows.iter().map(|row| row.parse).sum();
+    let solve: Duration = rows.iter().map(|row| row.solve).sum();
+    let mut footer = vec![String::new(); 3 - skip];
+    footer[0] = "Total".to_string();
+    footer.extend([
+        String::new(),
+        format!("{parse:.2?}"),
+        format!("{solve:.2?}"),
+        format!("{:.2?}", parse + solve),
+    ]);
+
+    let mut headers = ["Year", "Day", "Part", "Answer", "Parse", "Solve", "Total"][skip..].to_vec();
+    if counted {
+        let allocations = rows.iter().filter_map(|row| row.allocations);
+        footer.extend([
+            allocations
+                .clone()
+                .map(|a| a.count)
+                .sum::<u64>()
+                .to_string(),
+            allocations
+                .clone()
+                .map(|a| a.bytes)
+                .sum::<u64>()
+                .to_string(),
+            // de hoogste piek, de oplossingen draaien na elkaar
+            allocations.map(|a| a.peak).max().unwrap_or(0).to_string(),
+        ]);
+        headers.extend(["Allocs", "Bytes", "Peak"]);
+    }
+
+    grid(&headers, &cells, Some(&footer))
+}
+
+/// Right aligned columns, with the footer below a separator.
+pub fn grid(headers: &[&str], cells: &[Vec<String>], footer: Option<&[String]>) -> String {
+    let headers: Vec<String> = headers.iter().map(|header| header.to_string()).collect();
+
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+    for line in cells.iter().map(Vec::as_slice).chain(footer) {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    write_line(&mut out, &headers, &widths);
+    writeln!(out, "{separator}").unwrap();
+    for line in cells {
+        write_line(&mut out, line, &widths);
+    }
+    if let Some(footer) = footer {
+        writeln!(out, "{separator}").unwrap();
+        write_line(&mut out, footer, &widths);
+    }
+
+    out
+}
+
+/// The same cells as a Markdown table.
+pub fn markdown(headers: &[&str], cells: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    writeln!(out, "| {} |", headers.join(" | ")).unwrap();
+    writeln!(out, "|{}", "---:|".repeat(headers.len())).unwrap();
+    for line in cells {
+        writeln!(out, "| {} |", line.join(" | ")).unwrap();
+    }
+
+    out
+}
+
+fn write_line(out: &mut String, cells: &[String], widths: &[usize]) {
+    let line = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, &width)| format!("{cell:>width$}"))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    writeln!(out, "{line}").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use indoc::indoc;
+
+    use aoc_core::alloc::Allocations;
+
+    use super::{Row, render};
+
+    #[test]
+    fn renders_aligned_table_with_total() {
+        let rows = [
+            Row {
+                year: 2025,
+                day: 1,
+                part: 1,
+                answer: "3".to_string(),
+                parse: Duration::from_micros(1000),
+                solve: Duration::from_micros(500),
+                allocations: None,
+            },
+            Row {
+                year: 2025,
+                day: 11,
+                part: 2,
+                answer: "526811953334940".to_string(),
+                parse: Duration::from_micros(200),
+                solve: Duration::from_micros(300),
+                allocations: None,
+            },
+        ];
+
+        let expected = indoc! {"
+              Day | Part |          Answer |    Parse |    Solve |    Total
+            ------+------+-----------------+----------+----------+---------
+                1 |    1 |               3 |   1.00ms | 500.00µs |   1.50ms
+               11 |    2 | 526811953334940 | 200.00µs | 300.00µs | 500.00µs
+            ------+------+-----------------+----------+----------+---------
+            Total |      |                 |   1.20ms | 800.00µs |   2.00ms
+        "};
+
+        assert_eq!(render(&rows), expected);
+    }
+
+    #[test]
+    fn shows_the_year_of_other_events() {
+        let row = |year| Row {
+            year,
+            day: 5,
+            part: 1,
+            answer: "7".to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+            allocations: None,
+        };
+
+        let expected = indoc! {"
+             Year | Day | Part | Answer |  Parse |  Solve |  Total
+            ------+-----+------+--------+--------+--------+-------
+             2024 |   5 |    1 |      7 | 0.00ns | 0.00ns | 0.00ns
+             2025 |   5 |    1 |      7 | 0.00ns | 0.00ns | 0.00ns
+            ------+-----+------+--------+--------+--------+-------
+            Total |     |      |        | 0.00ns | 0.00ns | 0.00ns
+        "};
+
+        assert_eq!(render(&[row(2024), row(2025)]), expected);
+        assert!(render(&[row(2025)]).starts_with("  Day |"));
+    }
+
+    #[test]
+    fn shows_counted_allocations() {
+        let row = |day, count, peak| Row {
+            year: 2025,
+            day,
+            part: 1,
+            answer: "7".to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+            allocations: Some(Allocations {
+                count,
+                bytes: count * 100,
+                peak,
+            }),
+        };
+
+        let expected = indoc! {"
+              Day | Part | Answer |  Parse |  Solve |  Total | Allocs | Bytes | Peak
+            ------+------+--------+--------+--------+--------+--------+-------+-----
+                1 |    1 |      7 | 0.00ns | 0.00ns | 0.00ns |      2 |   200 |  150
+                2 |    1 |      7 | 0.00ns | 0.00ns | 0.00ns |     12 |  1200 |  900
+            ------+------+--------+--------+--------+--------+--------+-------+-----
+            Total |      |        | 0.00ns | 0.00ns | 0.00ns |     14 |  1400 |  900
+        "};
+
+        assert_eq!(render(&[row(1, 2, 150), row(2, 12, 900)]), expected);
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fmt::Write,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use tracing::{Subscriber, span};
+use tracing_subscriber::{Layer, layer::Context, prelude::*, registry::LookupSpan};
+
+/// The time spent in every span with the same path, e.g. `solve/union_find`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    pub name: &'static str,
+    pub busy: Duration,
+    pub calls: u32,
+    pub children: Vec<Node>,
+}
+
+struct Timing {
+    path: Vec<&'static str>,
+    busy: Duration,
+    entered: Option<Instant>,
+}
+
+/// The path of a closed span and how long it was entered.
+type Closed = (Vec<&'static str>, Duration);
+
+/// Records how long every span was entered, the time spent in its children
+/// included, once it closes.
+#[derive(Clone, Default)]
+pub struct Collector {
+    closed: Arc<Mutex<Vec<Closed>>>,
+}
+
+/// The collector of the process, installed as the global subscriber on the
+/// first call.
+pub fn collector() -> &'static Collector {
+    static COLLECTOR: OnceLock<Collector> = OnceLock::new();
+
+    COLLECTOR.get_or_init(|| {
+        let collector = Collector::default();
+        tracing::subscriber::set_global_default(
+            tracing_subscriber::registry().with(collector.clone()),
+        )
+        .expect("no other subscriber is installed");
+        collector
+    })
+}
+
+impl Collector {
+    /// The spans closed since the previous call, merged by path in the order
+    /// they were first closed.
+    pub fn take(&self) -> Vec<Node> {
+        let closed = std::mem::take(&mut *self.closed.lock().unwrap());
+
+        let mut nodes = Vec::new();
+        for (path, busy) in closed {
+            insert(&mut nodes, &path, busy);
+        }
+        nodes
+    }
+}
+
+fn insert(nodes: &mut Vec<Node>, path: &[&'static str], busy: Duration) {
+    let Some((&name, rest)) = path.split_first() else {
+        return;
+    };
+
+    let at = match nodes.iter().position(|node| node.name == name) {
+        Some(at) => at,
+        None => {
+            nodes.push(Node {
+                name,
+                busy: Duration::ZERO,
+                calls: 0,
+                children: Vec::new(),
+            });
+            nodes.len() - 1
+        }
+    };
+
+    let node = &mut nodes[at];
+    if rest.is_empty() {
+        node.busy += busy;
+        node.calls += 1;
+    } else {
+        insert(&mut node.children, rest, busy);
+    }
+}
+
+impl<S> Layer<S> for Collector
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        // spans entered on another thread, e.g. by rayon, have no parent
+        let mut path = span
+            .parent()
+            .and_then(|parent| {
+                let extensions = parent.extensions();
+                extensions.get::<Timing>().map(|timing| timing.path.clone())
+            })
+            .unwrap_or_default();
+        path.push(attrs.metadata().name());
+
+        span.extensions_mut().insert(Timing {
+            path,
+            busy: Duration::ZERO,
+            entered: None,
+        });
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id)
+            && let Some(timing) = span.extensions_mut().get_mut::<Timing>()
+        {
+            timing.entered = Some(Instant::now());
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id)
+            && let Some(timing) = span.extensions_mut().get_mut::<Timing>()
+            && let Some(entered) = timing.entered.take()
+        {
+            timing.busy += entered.elapsed();
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id)
+            && let Some(timing) = span.extensions_mut().remove::<Timing>()
+        {
+            self.closed.lock().unwrap().push((timing.path, timing.busy));
+        }
+    }
+}
+
+/// An indented tree below `name`, in nanoseconds.
+pub fn render(name: &str, nodes: &[Node]) -> String {
+    let mut lines = Vec::new();
+    flatten(nodes, 1, &mut lines);
+
+    let width = lines
+        .iter()
+        .map(|(depth, node)| depth * 2 + node.name.len())
+        .max()
+        .unwrap_or(0);
+    let digits = lines
+        .iter()
+        .map(|(_, node)| node.busy.as_nanos().to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!("{name}\n");
+    for (depth, node) in lines {
+        let label = format!("{:indent$}{}", "", node.name, indent = depth * 2);
+        write!(out, "{label:width$}  {:>digits$} ns", node.busy.as_nanos()).unwrap();
+        if node.calls > 1 {
+            write!(out, "  ({} calls)", node.calls).unwrap();
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn flatten<'a>(nodes: &'a [Node], depth: usize, lines: &mut Vec<(usize, &'a Node)>) {
+    for node in nodes {
+        lines.push((depth, node));
+        flatten(&node.children, depth + 1, lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use indoc::indoc;
+    use tracing_subscriber::prelude::*;
+
+    use super::{Collector, Node, render};
+
+    fn node(name: &'static str, nanos: u64, calls: u32, children: Vec<Node>) -> Node {
+        Node {
+            name,
+            busy: Duration::from_nanos(nanos),
+            calls,
+            children,
+        }
+    }
+
+    #[test]
+    fn take_merges_spans_by_path() {
+        let collector = Collector::default();
+        let subscriber = tracing_subscriber::registry().with(collector.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("parse").in_scope(|| {});
+            let _solve = tracing::info_span!("solve").entered();
+            for _ in 0..3 {
+                let _row = tracing::info_span!("reduce").entered();
+            }
+        });
+
+        let shape = |nodes: &[Node]| -> Vec<(&str, u32, usize)> {
+            nodes
+                .iter()
+                .map(|node| (node.name, node.calls, node.children.len()))
+                .collect()
+        };
+        let nodes = collector.take();
+        assert_eq!(shape(&nodes), [("parse", 1, 0), ("solve", 1, 1)]);
+        assert_eq!(shape(&nodes[1].children), [("reduce", 3, 0)]);
+        assert!(nodes[1].busy >= nodes[1].children[0].busy);
+        assert!(collector.take().is_empty());
+    }
+
+    #[test]
+    fn render_indents_children() {
+        let nodes = [
+            node("parse", 1_250, 1, Vec::new()),
+            node(
+                "solve",
+                903_000,
+                1,
+                vec![node("reduce_to_echelon_form", 41_007, 152, Vec::new())],
+            ),
+        ];
+
+        assert_eq!(
+            render("day-10-part-01", &nodes),
+            indoc! {"
+                day-10-part-01
+                  parse                       1250 ns
+                  solve                     903000 ns
+                    reduce_to_echelon_form   41007 ns  (152 calls)
+            "}
+        );
+    }
+}
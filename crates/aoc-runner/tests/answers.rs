@@ -0,0 +1,33 @@
+use std::{fs, path::Path};
+
+use aoc_runner::{
+    manifest::{self, Manifest, Problem},
+    registry::{SOLVERS, workspace_root},
+};
+
+/// Scaffolding leaves an empty `input.txt`.
+fn has_input(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+#[test]
+fn every_solver_matches_the_manifest() {
+    let root = workspace_root();
+    let manifest = Manifest::load(&root.join("answers.toml")).unwrap();
+
+    // a new crate has no answer until its input is downloaded
+    let mismatches: Vec<_> = manifest::verify(&manifest, &root, SOLVERS)
+        .into_iter()
+        .filter(|mismatch| {
+            !matches!(mismatch.problem, Problem::Missing) || has_input(&root.join(&mismatch.input))
+        })
+        .collect();
+    let report: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+
+    assert!(
+        mismatches.is_empty(),
+        "{} answers do not match:\n{}",
+        mismatches.len(),
+        report.join("\n")
+    );
+}
@@ -0,0 +1,157 @@
+mod mock;
+
+use std::{fs, path::Path};
+
+use aoc_runner::client::{self, Client, Download};
+use mock::{Route, Server};
+
+const INPUT: &str = "L68\nL30\nR48\n";
+
+fn crates(root: &Path, names: &[&str]) {
+    for name in names {
+        fs::create_dir_all(root.join("puzzles").join(name)).unwrap();
+    }
+}
+
+fn server() -> Server {
+    Server::start(vec![
+        Route {
+            method: "GET",
+            path: "/2025/day/1/input",
+            status: 200,
+            body: INPUT,
+        },
+        Route {
+            method: "GET",
+            path: "/2025/day/2/input",
+            status: 400,
+            body: "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        },
+    ])
+}
+
+#[test]
+fn download_writes_every_part_and_sends_the_session() {
+    let root = tempfile::tempdir().unwrap();
+    crates(root.path(), &["day-01-part-01", "day-01-part-02"]);
+    let server = server();
+    let client = Client::new(&server.url, "53616c7465645f5f\n");
+
+    let download = client::download(&client, root.path(), 2025, 1, false).unwrap();
+
+    assert_eq!(
+        download,
+        Download {
+            written: vec![
+                "puzzles/day-01-part-01/input.txt".into(),
+                "puzzles/day-01-part-02/input.txt".into(),
+            ],
+            fetched: true,
+        }
+    );
+    for part in ["day-01-part-01", "day-01-part-02"] {
+        let input = root.path().join("puzzles").join(part).join("input.txt");
+        assert_eq!(fs::read_to_string(input).unwrap(), INPUT);
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].header("Cookie"),
+        Some("session=53616c7465645f5f")
+    );
+}
+
+#[test]
+fn download_reuses_inputs_that_are_already_present() {
+    let root = tempfile::tempdir().unwrap();
+    crates(root.path(), &["day-01-part-01", "day-01-part-02"]);
+    fs::write(root.path().join("puzzles/day-01-part-01/input.txt"), "R1\n").unwrap();
+    let server = server();
+    let client = Client::new(&server.url, "session");
+
+    let first = client::download(&client, root.path(), 2025, 1, false).unwrap();
+    let second = client::download(&client, root.path(), 2025, 1, false).unwrap();
+
+    assert_eq!(
+        first,
+        Download {
+            written: vec!["puzzles/day-01-part-02/input.txt".into()],
+            fetched: false,
+        }
+    );
+    assert_eq!(second.written, Vec::<std::path::PathBuf>::new());
+    assert_eq!(
+        fs::read_to_string(root.path().join("puzzles/day-01-part-02/input.txt")).unwrap(),
+        "R1\n"
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn download_with_force_fetches_again() {
+    let root = tempfile::tempdir().unwrap();
+    crates(root.path(), &["day-01-part-01", "day-01-part-02"]);
+    fs::write(root.path().join("puzzles/day-01-part-01/input.txt"), "R1\n").unwrap();
+    let server = server();
+    let client = Client::new(&server.url, "session");
+
+    let download = client::download(&client, root.path(), 2025, 1, true).unwrap();
+
+    assert!(download.fetched);
+    assert_eq!(
+        fs::read_to_string(root.path().join("puzzles/day-01-part-01/input.txt")).unwrap(),
+        INPUT
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn download_reports_the_server_error() {
+    let root = tempfile::tempdir().unwrap();
+    crates(root.path(), &["day-02-part-01", "day-02-part-02"]);
+    let server = server();
+    let client = Client::new(&server.url, "expired");
+
+    let error = client::download(&client, root.path(), 2025, 2, false).unwrap_err();
+
+    assert_eq!(
+        error,
+        format!(
+            "{}/2025/day/2/input answered 400 Bad Request: Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            server.url
+        )
+    );
+    assert!(
+        !root
+            .path()
+            .join("puzzles/day-02-part-01/input.txt")
+            .exists()
+    );
+}
+
+#[test]
+fn download_needs_the_crate_directory() {
+    let root = tempfile::tempdir().unwrap();
+    let server = server();
+    let client = Client::new(&server.url, "session");
+
+    let error = client::download(&client, root.path(), 2025, 1, false).unwrap_err();
+
+    assert!(
+        error.ends_with("input.txt: the crate directory does not exist"),
+        "{error}"
+    );
+}
+
+#[test]
+fn download_needs_a_crate_of_that_year() {
+    let root = tempfile::tempdir().unwrap();
+    let server = server();
+    let client = Client::new(&server.url, "session");
+
+    let error = client::download(&client, root.path(), 2015, 1, false).unwrap_err();
+
+    assert_eq!(error, "there is no crate for 2015 day 1");
+    assert!(server.requests().is_empty());
+}
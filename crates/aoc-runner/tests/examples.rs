@@ -0,0 +1,42 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_runner::{description, registry::SOLVERS};
+use rstest::rstest;
+
+fn read_description(dir: &Path) -> Option<String> {
+    ["description.md", "description.txt", "description"]
+        .into_iter()
+        .find_map(|name| fs::read_to_string(dir.join(name)).ok())
+}
+
+/// One test per description file, so a new day gets its examples checked as
+/// soon as `aoc describe` wrote its description.
+#[rstest]
+fn example_matches_the_description(#[files("../../puzzles/**/description*")] path: PathBuf) {
+    let dir = path.parent().unwrap();
+    let solver = SOLVERS
+        .iter()
+        .find(|solver| dir.ends_with(solver.dir()))
+        .unwrap_or_else(|| panic!("{} is not registered", dir.display()));
+    if !solver.examples {
+        return;
+    }
+    let name = solver.name;
+
+    let part_one = dir.with_file_name(format!("day-{:02}-part-01", solver.day));
+    let examples = description::examples(
+        &fs::read_to_string(&path).unwrap(),
+        // part two may be described before part one is
+        &read_description(&part_one).unwrap_or_default(),
+    );
+
+    // descriptions copied in by hand have no emphasis, so no answers to check
+    for example in examples {
+        let outcome = (solver.run)(&example.input).unwrap_or_else(|e| panic!("{name}: {e}"));
+
+        assert_eq!(outcome.answer, example.answer, "{name}: {example:?}");
+    }
+}
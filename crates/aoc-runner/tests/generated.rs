@@ -0,0 +1,66 @@
+use aoc_core::DEFAULT_YEAR;
+use aoc_gen::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12};
+use aoc_runner::registry::SOLVERS;
+use rstest::rstest;
+
+/// Small enough to solve quickly in a debug build. Only the days of
+/// [`DEFAULT_YEAR`] up to 12 have a generator.
+fn generate(year: u16, day: u8, seed: u64) -> Option<String> {
+    if year != DEFAULT_YEAR {
+        return None;
+    }
+
+    let input = match day {
+        1 => day01::Rotations::default().generate(seed),
+        2 => day02::IdRanges {
+            span: 2_000,
+            ..Default::default()
+        }
+        .generate(seed),
+        3 => day03::Banks::default().generate(seed),
+        4 => day04::Rolls::default().generate(seed),
+        5 => day05::Inventory::default().generate(seed),
+        6 => day06::Worksheet::default().generate(seed),
+        7 => day07::Manifold::default().generate(seed),
+        8 => day08::JunctionBoxes {
+            count: 300,
+            ..Default::default()
+        }
+        .generate(seed),
+        9 => day09::RedTiles {
+            corners: 100,
+            ..Default::default()
+        }
+        .generate(seed),
+        10 => day10::Machines {
+            count: 10,
+            ..Default::default()
+        }
+        .generate(seed),
+        11 => day11::Devices::default().generate(seed),
+        12 => day12::Regions::default().generate(seed),
+        _ => return None,
+    };
+    Some(input)
+}
+
+#[rstest]
+fn every_solver_accepts_generated_input(#[values(1, 2, 3)] seed: u64) {
+    for solver in SOLVERS {
+        let Some(input) = generate(solver.year, solver.day, seed) else {
+            continue;
+        };
+        if let Err(e) = (solver.run)(&input) {
+            panic!("{} rejects generated input: {e}", solver.name);
+        }
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for day in 1..=12 {
+        let generate = |seed| generate(DEFAULT_YEAR, day, seed).unwrap();
+        assert_eq!(generate(42), generate(42), "day {day}");
+        assert_ne!(generate(42), generate(43), "day {day}");
+    }
+}
@@ -0,0 +1,125 @@
+mod mock;
+
+use std::{path::Path, time::Duration};
+
+use aoc_runner::{
+    client::{self, Client, Hint, Verdict},
+    manifest::Manifest,
+    registry::{self, Solver},
+};
+use mock::{Route, Server};
+
+const INPUT: &str = "puzzles/day-01-part-02/input.txt";
+
+fn solver() -> &'static Solver {
+    registry::select(&[2025], &[1], &[2]).next().unwrap()
+}
+
+fn server(page: &'static str) -> Server {
+    Server::start(vec![Route {
+        method: "POST",
+        path: "/2025/day/1/answer",
+        status: 200,
+        body: page,
+    }])
+}
+
+#[test]
+fn submit_posts_the_answer_and_records_it_when_correct() {
+    let server = server(include_str!("fixtures/answer/correct.html"));
+    let client = Client::new(&server.url, "session");
+    let mut manifest = Manifest::new();
+
+    let verdict =
+        client::submit(&client, &mut manifest, solver(), Path::new(INPUT), "5831").unwrap();
+
+    assert_eq!(verdict, Verdict::Correct);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].body, "level=2&answer=5831");
+    assert_eq!(
+        requests[0].header("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(requests[0].header("Cookie"), Some("session=session"));
+
+    let entry = manifest.entries(2025, 1, 2).next().unwrap();
+    assert_eq!(entry.input, Path::new(INPUT));
+    assert_eq!(entry.sha256, manifest.hash(2025, 1, 2, "5831"));
+}
+
+#[test]
+fn submit_does_not_record_wrong_answers() {
+    let server = server(include_str!("fixtures/answer/too_low.html"));
+    let client = Client::new(&server.url, "session");
+    let mut manifest = Manifest::new();
+
+    let verdict = client::submit(&client, &mut manifest, solver(), Path::new(INPUT), "12").unwrap();
+
+    assert_eq!(
+        verdict,
+        Verdict::Wrong {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(60)),
+        }
+    );
+    assert_eq!(manifest.entries(2025, 1, 2).count(), 0);
+}
+
+#[test]
+fn submit_reports_how_long_to_wait() {
+    let server = server(include_str!("fixtures/answer/wait.html"));
+    let client = Client::new(&server.url, "session");
+
+    let verdict = client::submit(
+        &client,
+        &mut Manifest::new(),
+        solver(),
+        Path::new(INPUT),
+        "12",
+    )
+    .unwrap();
+
+    assert_eq!(verdict, Verdict::Wait(Duration::from_secs(65)));
+}
+
+#[test]
+fn submit_checks_known_answers_locally() {
+    let server = server(include_str!("fixtures/answer/too_high.html"));
+    let client = Client::new(&server.url, "session");
+    let mut manifest = Manifest::new();
+    manifest.record(2025, 1, 2, INPUT.into(), "5831");
+
+    let known = client::submit(&client, &mut manifest, solver(), Path::new(INPUT), "5831");
+    let other = client::submit(&client, &mut manifest, solver(), Path::new(INPUT), "5832");
+
+    assert_eq!(known, Ok(Verdict::Correct));
+    assert_eq!(
+        other,
+        Err("day-01-part-02: 5832 differs from the accepted answer".to_string())
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn submit_rejects_unknown_pages() {
+    let server = server("<main><article><p>Something else</p></article></main>");
+    let client = Client::new(&server.url, "session");
+
+    let error = client::submit(
+        &client,
+        &mut Manifest::new(),
+        solver(),
+        Path::new(INPUT),
+        "1",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        format!(
+            "{}/2025/day/1/answer answered with an unknown page",
+            server.url
+        )
+    );
+}
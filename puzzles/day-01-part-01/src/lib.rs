@@ -0,0 +1,45 @@
+use aoc_core::{ParseError, Solution};
+use day_01::{Dial, Instruction, count_zero_landings, instructions};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+
+    type Input<'a> = Vec<Instruction>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        instructions(input.as_bytes()).collect()
+    }
+
+    fn solve(instructions: Self::Input<'_>) -> Self::Output {
+        count_zero_landings(Dial::new(), instructions)
+    }
+}
+
+pub fn solve(input: &str) -> usize {
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn try_solve_reports_line_of_invalid_steps() {
+        let error = super::try_solve("L68\nL30\nR4x\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 3, 2));
+        assert_eq!(error.bytes, b"4x");
+    }
+
+    #[test]
+    fn solve_example_input() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let result = super::solve(input);
+        assert_eq!(result, 3);
+    }
+}
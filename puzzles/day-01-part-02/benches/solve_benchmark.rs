@@ -1,14 +1,52 @@
-use criterion::{Criterion, criterion_group, criterion_main};
-use day_01_part_02::solve;
+use aoc_core::Solution;
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day01::Rotations};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use day_01_part_02::Puzzle;
+
+aoc_core::count_allocations!();
 
 fn bench_solve(c: &mut Criterion) {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+
+    bench_solution::<Puzzle>(c, "day-01-part-02", &input);
+}
 
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(
+        c,
+        "day-01-part-02",
+        &[1_000, 10_000, 100_000, 1_000_000],
+        |count| {
+            Rotations {
+                count,
+                ..Default::default()
+            }
+            .generate(BENCH_SEED)
+        },
+    );
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let input = Rotations {
+        count: 10_000_000,
+        ..Default::default()
+    }
+    .generate(BENCH_SEED);
 
-    let answer = solve(&input);
-    assert_eq!(answer, 5831);
+    let mut group = c.benchmark_group("day-01-part-02/scan");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("sequential", |b| b.iter(|| Puzzle::run(&input)));
+    group.bench_function("parallel", |b| {
+        b.iter(|| day_01::scan::count_zero_passes(input.as_bytes()).unwrap())
+    });
+    group.finish();
 }
 
 criterion_group!(benches, bench_solve);
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale, bench_scan
+}
+criterion_main!(benches, scaling);
@@ -1,7 +1,7 @@
 use day_01_part_02::solve;
 
 fn main() {
-    let input = std::fs::read_to_string("puzzles/day-01-part-02/input.txt").unwrap();
+    let input = aoc_core::input!(std::env::args_os().nth(1)).unwrap_or_else(|e| panic!("{e}"));
 
     let instant = std::time::Instant::now();
     let nr_of_revolutions_over_zero = solve(&input);
@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use aoc_core::ParseError;
+
+use crate::{Dial, Instruction, Lines, lines};
+
+/// What a turn did to the dial. `line` is the line of the turn in the input,
+/// counting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// Went past zero `times` without stopping there.
+    PassedZero { line: usize, times: usize },
+    /// Stopped at zero.
+    LandedOnZero { line: usize },
+    /// Where the turn stopped, after the zero events of the same turn.
+    Turned { line: usize, position: usize },
+}
+
+/// The events of every turn of an input, see [`Dial::events`].
+///
+/// The landings are the answer of part one, the landings and the passes
+/// together that of part two, except for a turn of zero steps at zero, which
+/// lands there without a revolution.
+pub struct Events<'a> {
+    dial: Dial,
+    input: &'a [u8],
+    lines: Lines<'a>,
+    pending: VecDeque<Event>,
+    failed: bool,
+}
+
+impl Dial {
+    /// Turns the dial for every line of `input`, one event at a time.
+    pub fn events(self, input: &[u8]) -> Events<'_> {
+        Events {
+            dial: self,
+            input,
+            lines: lines(input),
+            pending: VecDeque::with_capacity(3),
+            failed: false,
+        }
+    }
+}
+
+impl Events<'_> {
+    /// The dial after the turns so far.
+    pub fn dial(&self) -> &Dial {
+        &self.dial
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Result<Event, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(Ok(event));
+        }
+        if self.failed {
+            return None;
+        }
+
+        let (line_nr, line) = self.lines.next()?;
+        let instruction = match Instruction::parse(self.input, line) {
+            Ok(instruction) => instruction,
+            Err(e) => {
+                self.failed = true;
+                return Some(Err(e));
+            }
+        };
+
+        let before = self.dial.revolutions;
+        self.dial.turn(instruction);
+        let position = self.dial.current_position();
+        let landed = position == 0;
+        let passed = (self.dial.revolutions - before).saturating_sub(usize::from(landed));
+
+        if passed > 0 {
+            self.pending.push_back(Event::PassedZero {
+                line: line_nr,
+                times: passed,
+            });
+        }
+        if landed {
+            self.pending
+                .push_back(Event::LandedOnZero { line: line_nr });
+        }
+        self.pending.push_back(Event::Turned {
+            line: line_nr,
+            position,
+        });
+
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Event::{self, LandedOnZero, PassedZero, Turned};
+    use crate::Dial;
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn events_of_the_example() {
+        let events: Vec<Event> = Dial::new()
+            .events(b"L68\nL30\nR48\n\nL5\nR160\nL55\n")
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            events,
+            [
+                PassedZero { line: 1, times: 1 },
+                Turned {
+                    line: 1,
+                    position: 82
+                },
+                Turned {
+                    line: 2,
+                    position: 52
+                },
+                LandedOnZero { line: 3 },
+                Turned {
+                    line: 3,
+                    position: 0
+                },
+                Turned {
+                    line: 5,
+                    position: 95
+                },
+                PassedZero { line: 6, times: 2 },
+                Turned {
+                    line: 6,
+                    position: 55
+                },
+                LandedOnZero { line: 7 },
+                Turned {
+                    line: 7,
+                    position: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_add_up_to_both_answers() {
+        let (mut landings, mut passes) = (0, 0);
+        for event in Dial::new().events(EXAMPLE.as_bytes()) {
+            match event.unwrap() {
+                LandedOnZero { .. } => landings += 1,
+                PassedZero { times, .. } => passes += times,
+                Turned { .. } => {}
+            }
+        }
+
+        assert_eq!(landings, 3);
+        assert_eq!(landings + passes, 6);
+    }
+
+    #[test]
+    fn zero_steps_at_zero_is_a_landing() {
+        let events: Vec<Event> = Dial::new()
+            .events(b"L50\nR0\n")
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            events,
+            [
+                LandedOnZero { line: 1 },
+                Turned {
+                    line: 1,
+                    position: 0
+                },
+                LandedOnZero { line: 2 },
+                Turned {
+                    line: 2,
+                    position: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_stop_at_an_invalid_turn() {
+        let mut events = Dial::new().events(b"R10\nU5\nR3\n");
+
+        let error = events.nth(1).unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(events.next().is_none());
+        assert_eq!(events.dial().current_position(), 60);
+    }
+}
@@ -0,0 +1,390 @@
+//! The dial of day 1 and its `L68`/`R48` instructions, shared by both parts.
+
+pub mod events;
+pub mod lock;
+pub mod scan;
+
+use aoc_core::{ParseError, parse_number};
+
+const DAY: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// One line of the input, like `L68`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub steps: usize,
+}
+
+impl Instruction {
+    pub fn new(direction: Direction, steps: usize) -> Self {
+        Self { direction, steps }
+    }
+
+    /// Parses `line`, a subslice of `input`, so errors point into `input`.
+    pub fn parse(input: &[u8], line: &[u8]) -> Result<Self, ParseError> {
+        let Some((&direction, steps)) = line.split_first() else {
+            return Err(ParseError::at(DAY, input, line, "expected a turn"));
+        };
+
+        let direction = match direction {
+            b'R' => Direction::Clockwise,
+            b'L' => Direction::CounterClockwise,
+            _ => {
+                return Err(ParseError::at(
+                    DAY,
+                    input,
+                    &line[..1],
+                    "invalid direction, expected `L` or `R`",
+                ));
+            }
+        };
+
+        let steps = parse_number(DAY, input, steps)?;
+        Ok(Self { direction, steps })
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value.as_bytes(), value.as_bytes())
+    }
+}
+
+/// The non-empty lines of an input with their line numbers, counting from 1.
+pub struct Lines<'a> {
+    rest: &'a [u8],
+    line_nr: usize,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (usize, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.rest.is_empty() {
+            let end = self
+                .rest
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .unwrap_or(self.rest.len());
+            let line = &self.rest[..end];
+            self.rest = self.rest.get(end + 1..).unwrap_or_default();
+            self.line_nr += 1;
+
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if !line.is_empty() {
+                return Some((self.line_nr, line));
+            }
+        }
+
+        None
+    }
+}
+
+pub fn lines(input: &[u8]) -> Lines<'_> {
+    Lines {
+        rest: input,
+        line_nr: 0,
+    }
+}
+
+/// Parses every line of `input` without copying it.
+pub fn instructions(input: &[u8]) -> impl Iterator<Item = Result<Instruction, ParseError>> {
+    lines(input).map(move |(_, line)| Instruction::parse(input, line))
+}
+
+/// How often a turn ends at zero, the answer of part one.
+pub fn count_zero_landings(
+    mut dial: Dial,
+    instructions: impl IntoIterator<Item = Instruction>,
+) -> usize {
+    instructions
+        .into_iter()
+        .filter(|&instruction| {
+            dial.turn(instruction);
+            dial.current_position() == 0
+        })
+        .count()
+}
+
+/// How often the dial points at zero during the turns, the landings
+/// included, the answer of part two.
+pub fn count_zero_passes(
+    mut dial: Dial,
+    instructions: impl IntoIterator<Item = Instruction>,
+) -> usize {
+    for instruction in instructions {
+        dial.turn(instruction);
+    }
+
+    dial.revolutions
+}
+
+#[derive(Debug)]
+pub struct Dial {
+    position: usize,
+    size: usize,
+    revolutions: usize,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dial {
+    /// The dial of the puzzle: 100 positions, starting at 50.
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    pub fn builder() -> DialBuilder {
+        DialBuilder {
+            size: 100,
+            start: 50,
+        }
+    }
+
+    pub fn turn(&mut self, instruction: Instruction) {
+        match instruction.direction {
+            Direction::Clockwise => self.turn_clockwise(instruction.steps),
+            Direction::CounterClockwise => self.turn_counter_clockwise(instruction.steps),
+        }
+    }
+
+    #[inline]
+    fn turn_clockwise(&mut self, steps: usize) {
+        let (actual, revolutions) = self.advance(self.position, steps);
+
+        self.position = actual;
+        self.revolutions += revolutions;
+    }
+
+    /// Clockwise in the mirrored dial, where zero stays zero.
+    #[inline]
+    fn turn_counter_clockwise(&mut self, steps: usize) {
+        let (actual, revolutions) = self.advance(self.mirror(self.position), steps);
+
+        self.position = self.mirror(actual);
+        self.revolutions += revolutions;
+    }
+
+    /// The position `steps` clockwise of `from` and how often that passes or
+    /// lands on zero. Like `(from + steps) / size`, without overflowing.
+    #[inline]
+    fn advance(&self, from: usize, steps: usize) -> (usize, usize) {
+        let n = self.size;
+        let (laps, rest) = (steps / n, steps % n);
+        let room = n - from;
+
+        if rest >= room {
+            (rest - room, laps + 1)
+        } else {
+            (from + rest, laps)
+        }
+    }
+
+    #[inline]
+    fn mirror(&self, position: usize) -> usize {
+        (self.size - position) % self.size
+    }
+
+    pub fn current_position(&self) -> usize {
+        self.position
+    }
+
+    /// How often the dial passed or landed on zero.
+    pub fn revolutions(&self) -> usize {
+        self.revolutions
+    }
+}
+
+pub struct DialBuilder {
+    size: usize,
+    start: usize,
+}
+
+impl DialBuilder {
+    /// The number of positions, `0..size`.
+    pub fn size(self, size: usize) -> Self {
+        Self { size, ..self }
+    }
+
+    pub fn start(self, start: usize) -> Self {
+        Self { start, ..self }
+    }
+
+    pub fn build(self) -> Dial {
+        assert!(self.size > 0, "a dial needs at least one position");
+        assert!(
+            self.start < self.size,
+            "start {} is not on a dial of size {}",
+            self.start,
+            self.size
+        );
+
+        Dial {
+            position: self.start,
+            size: self.size,
+            revolutions: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        Dial,
+        Direction::{Clockwise, CounterClockwise},
+        Instruction, count_zero_landings, count_zero_passes, instructions, lines,
+    };
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    fn turned(instructions: &[(super::Direction, usize)]) -> Dial {
+        let mut dial = Dial::new();
+        for &(direction, steps) in instructions {
+            dial.turn(Instruction::new(direction, steps));
+        }
+        dial
+    }
+
+    #[rstest]
+    #[case(&[(CounterClockwise, 10)], 40, 0)]
+    #[case(&[(Clockwise, 10)], 60, 0)]
+    #[case(&[(Clockwise, 40)], 90, 0)]
+    #[case(&[(CounterClockwise, 60)], 90, 1)]
+    #[case(&[(Clockwise, 60)], 10, 1)]
+    #[case(&[(Clockwise, 50)], 0, 1)]
+    #[case(&[(CounterClockwise, 50)], 0, 1)]
+    #[case(&[(Clockwise, 51)], 1, 1)]
+    #[case(&[(CounterClockwise, 51)], 99, 1)]
+    #[case(&[(Clockwise, 249)], 99, 2)]
+    #[case(&[(Clockwise, 250)], 0, 3)]
+    #[case(&[(CounterClockwise, 249)], 1, 2)]
+    #[case(&[(CounterClockwise, 250)], 0, 3)]
+    #[case(&[(Clockwise, 1000)], 50, 10)]
+    #[case(&[(CounterClockwise, 1000)], 50, 10)]
+    #[case(&[(CounterClockwise, 50), (CounterClockwise, 5)], 95, 1)]
+    #[case(&[(CounterClockwise, 50), (Clockwise, 105)], 5, 2)]
+    fn dial_turns(
+        #[case] instructions: &[(super::Direction, usize)],
+        #[case] position: usize,
+        #[case] revolutions: usize,
+    ) {
+        let dial = turned(instructions);
+
+        assert_eq!(dial.current_position(), position);
+        assert_eq!(dial.revolutions(), revolutions);
+    }
+
+    #[rstest]
+    #[case(1, 0, "R1\nL7\nR0", 3, 8)]
+    #[case(100, 50, "L50\nR0\nL0", 3, 1)]
+    #[case(2, 1, "R1\nR2\nL3\nL4", 2, 5)]
+    #[case(7, 3, "R4\nL14\nR25", 2, 6)]
+    #[case(100, 50, EXAMPLE, 3, 6)]
+    #[case(usize::MAX, usize::MAX - 1, "R5\nL5\nL999", 0, 2)]
+    #[case(
+        usize::MAX,
+        3,
+        "L3\nR18446744073709551615\nL18446744073709551614",
+        2,
+        2
+    )]
+    fn counts_landings_and_passes_for_any_size(
+        #[case] size: usize,
+        #[case] start: usize,
+        #[case] input: &str,
+        #[case] landings: usize,
+        #[case] passes: usize,
+    ) {
+        let dial = || Dial::builder().size(size).start(start).build();
+        let instructions = || instructions(input.as_bytes()).map(Result::unwrap);
+
+        assert_eq!(count_zero_landings(dial(), instructions()), landings);
+        assert_eq!(count_zero_passes(dial(), instructions()), passes);
+    }
+
+    #[test]
+    fn dial_on_one_position_passes_zero_every_step() {
+        let mut dial = Dial::builder().size(1).start(0).build();
+
+        dial.turn(Instruction::new(Clockwise, usize::MAX - 1));
+        dial.turn(Instruction::new(CounterClockwise, 1));
+        assert_eq!(dial.current_position(), 0);
+        assert_eq!(dial.revolutions(), usize::MAX);
+    }
+
+    #[test]
+    fn dial_ends_where_the_example_says() {
+        let dial = turned(&[
+            (CounterClockwise, 68),
+            (CounterClockwise, 30),
+            (Clockwise, 48),
+        ]);
+
+        assert_eq!(dial.current_position(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "start 5 is not on a dial of size 5")]
+    fn dial_builder_rejects_start_beyond_size() {
+        Dial::builder().size(5).start(5).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one position")]
+    fn dial_builder_rejects_empty_dial() {
+        Dial::builder().size(0).start(0).build();
+    }
+
+    #[rstest]
+    #[case("R25", Clockwise, 25)]
+    #[case("L30", CounterClockwise, 30)]
+    #[case("R250", Clockwise, 250)]
+    fn instruction_from_str(
+        #[case] line: &str,
+        #[case] direction: super::Direction,
+        #[case] steps: usize,
+    ) {
+        assert_eq!(
+            Instruction::try_from(line).unwrap(),
+            Instruction::new(direction, steps)
+        );
+    }
+
+    #[test]
+    fn instruction_rejects_unknown_direction() {
+        let error = Instruction::try_from("X25").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.bytes, b"X");
+    }
+
+    #[test]
+    fn lines_skip_empty_lines_and_carriage_returns() {
+        let lines: Vec<_> = lines(b"R1\r\n\nL2\n").collect();
+
+        assert_eq!(lines, [(1, &b"R1"[..]), (3, &b"L2"[..])]);
+    }
+
+    #[test]
+    fn instructions_report_the_line_of_invalid_steps() {
+        let error = instructions(b"L68\nL30\nR4x\n")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert_eq!((error.day, error.line, error.column), (1, 3, 2));
+        assert_eq!(error.bytes, b"4x");
+    }
+}
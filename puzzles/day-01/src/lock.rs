@@ -0,0 +1,275 @@
+//! A combination lock of several coupled dials, like an odometer: every time
+//! a ring wraps around, the next ring turns one position the same way.
+
+use aoc_core::{ParseError, parse_number};
+
+use crate::{DAY, Dial, Direction, Instruction, lines};
+
+/// A turn of one ring, like `2:R40`. A turn without a ring, like `R40`,
+/// turns the first one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RingInstruction {
+    /// Counting from 1, like in the input.
+    pub ring: usize,
+    pub instruction: Instruction,
+}
+
+impl RingInstruction {
+    /// Parses `line`, a subslice of `input`, for a lock of `rings` rings.
+    pub fn parse(input: &[u8], line: &[u8], rings: usize) -> Result<Self, ParseError> {
+        let Some(colon) = line.iter().position(|&byte| byte == b':') else {
+            return Ok(Self {
+                ring: 1,
+                instruction: Instruction::parse(input, line)?,
+            });
+        };
+
+        let digits = &line[..colon];
+        let ring: usize = parse_number(DAY, input, digits)?;
+        if !(1..=rings).contains(&ring) {
+            return Err(ParseError::at(
+                DAY,
+                input,
+                digits,
+                format!("no such ring, the lock has rings 1 to {rings}"),
+            ));
+        }
+
+        Ok(Self {
+            ring,
+            instruction: Instruction::parse(input, &line[colon + 1..])?,
+        })
+    }
+}
+
+/// A combination to look for, like `0,1`: the position of every ring, the
+/// first ring first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination(Vec<usize>);
+
+impl Combination {
+    /// Parses `line`, a subslice of `input`, with one position per ring of
+    /// `lock`.
+    pub fn parse(input: &[u8], line: &[u8], lock: &Lock) -> Result<Self, ParseError> {
+        let positions = line
+            .split(|&byte| byte == b',')
+            .map(|digits| parse_number(DAY, input, digits))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        if positions.len() != lock.rings() {
+            return Err(ParseError::at(
+                DAY,
+                input,
+                line,
+                format!(
+                    "expected {} positions, one per ring, got {}",
+                    lock.rings(),
+                    positions.len()
+                ),
+            ));
+        }
+
+        Ok(Self(positions))
+    }
+}
+
+#[derive(Debug)]
+pub struct Lock {
+    rings: Vec<Dial>,
+}
+
+impl Lock {
+    /// The first ring is the one that turns fastest, every ring after it
+    /// turns once per revolution of the one before.
+    pub fn new(rings: impl IntoIterator<Item = Dial>) -> Self {
+        let rings: Vec<Dial> = rings.into_iter().collect();
+        assert!(!rings.is_empty(), "a lock needs at least one ring");
+
+        Self { rings }
+    }
+
+    pub fn rings(&self) -> usize {
+        self.rings.len()
+    }
+
+    /// The position of every ring, the first ring first.
+    pub fn combination(&self) -> Vec<usize> {
+        self.rings.iter().map(Dial::current_position).collect()
+    }
+
+    pub fn displays(&self, target: &Combination) -> bool {
+        assert_eq!(
+            target.0.len(),
+            self.rings(),
+            "the combination is for a lock of another number of rings"
+        );
+
+        self.rings
+            .iter()
+            .map(Dial::current_position)
+            .eq(target.0.iter().copied())
+    }
+
+    /// Turns a ring and carries its revolutions over to the rings after it.
+    /// The revolutions of the last ring are lost.
+    pub fn turn(&mut self, turn: RingInstruction) {
+        let mut instruction = turn.instruction;
+
+        for ring in &mut self.rings[turn.ring - 1..] {
+            if instruction.steps == 0 {
+                break;
+            }
+
+            let from = ring.current_position();
+            let revolutions = ring.revolutions();
+            ring.turn(instruction);
+            let hits = ring.revolutions() - revolutions;
+
+            // counter-clockwise the ring wraps when it leaves zero, but the
+            // dial counts arriving there
+            let wraps = match instruction.direction {
+                Direction::Clockwise => hits,
+                Direction::CounterClockwise => {
+                    hits + usize::from(from == 0) - usize::from(ring.current_position() == 0)
+                }
+            };
+            instruction.steps = wraps;
+        }
+    }
+}
+
+/// The lines of `input` after which `lock` displays `target`.
+pub fn lines_displaying(
+    mut lock: Lock,
+    input: &[u8],
+    target: &Combination,
+) -> Result<Vec<usize>, ParseError> {
+    let mut displaying = Vec::new();
+
+    for (line_nr, line) in lines(input) {
+        lock.turn(RingInstruction::parse(input, line, lock.rings())?);
+
+        if lock.displays(target) {
+            displaying.push(line_nr);
+        }
+    }
+
+    Ok(displaying)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Combination, Lock, RingInstruction, lines_displaying};
+    use crate::{
+        Dial,
+        Direction::{Clockwise, CounterClockwise},
+        Instruction,
+    };
+
+    fn lock(sizes: &[usize]) -> Lock {
+        Lock::new(
+            sizes
+                .iter()
+                .map(|&size| Dial::builder().size(size).start(0).build()),
+        )
+    }
+
+    fn turned(mut lock: Lock, input: &str) -> Vec<usize> {
+        for (_, line) in crate::lines(input.as_bytes()) {
+            lock.turn(RingInstruction::parse(input.as_bytes(), line, lock.rings()).unwrap());
+        }
+        lock.combination()
+    }
+
+    #[rstest]
+    #[case(&[10, 10, 10], "1:R123", &[3, 2, 1])]
+    #[case(&[10, 10, 10], "1:R123\n1:L4", &[9, 1, 1])]
+    #[case(&[10, 10, 10], "R123\nL4", &[9, 1, 1])]
+    #[case(&[10, 10, 10], "1:L1", &[9, 9, 9])]
+    #[case(&[10, 10, 10], "1:R10\n1:L10", &[0, 0, 0])]
+    #[case(&[10, 10, 10], "2:R15", &[0, 5, 1])]
+    #[case(&[10, 10, 10], "3:R15", &[0, 0, 5])]
+    #[case(&[60, 60, 24], "1:R3600\n1:R59\n1:R1", &[0, 1, 1])]
+    #[case(&[2, 2], "1:R4", &[0, 0])]
+    #[case(&[1, 3], "1:R4\n1:L2", &[0, 2])]
+    fn rings_carry_like_an_odometer(
+        #[case] sizes: &[usize],
+        #[case] input: &str,
+        #[case] expected: &[usize],
+    ) {
+        assert_eq!(turned(lock(sizes), input), expected);
+    }
+
+    #[test]
+    fn ring_instruction_defaults_to_the_first_ring() {
+        let turn = RingInstruction::parse(b"L68", b"L68", 3).unwrap();
+
+        assert_eq!(
+            turn,
+            RingInstruction {
+                ring: 1,
+                instruction: Instruction::new(CounterClockwise, 68),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("4:R1", 1, "4")]
+    #[case("0:R1", 1, "0")]
+    #[case("x:R1", 1, "x")]
+    #[case("2:U1", 3, "U")]
+    fn ring_instruction_rejects(#[case] line: &str, #[case] column: usize, #[case] bytes: &str) {
+        let error = RingInstruction::parse(line.as_bytes(), line.as_bytes(), 3).unwrap_err();
+
+        assert_eq!(error.column, column);
+        assert_eq!(error.bytes, bytes.as_bytes());
+    }
+
+    fn combination(query: &str, lock: &Lock) -> Combination {
+        Combination::parse(query.as_bytes(), query.as_bytes(), lock).unwrap()
+    }
+
+    #[test]
+    fn lines_displaying_the_target() {
+        let input = "1:R5\n1:R5\n\n2:L1\n1:R10\n";
+        let lock = lock(&[10, 10]);
+        let target = combination("0,1", &lock);
+
+        let lines = lines_displaying(lock, input.as_bytes(), &target).unwrap();
+        assert_eq!(lines, [2, 5]);
+    }
+
+    #[test]
+    fn lines_displaying_reports_the_line_of_an_unknown_ring() {
+        let lock = lock(&[10, 10]);
+        let target = combination("0,0", &lock);
+
+        let error = lines_displaying(lock, b"1:R5\n3:R5\n", &target).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[rstest]
+    #[case("0", 1, "0")]
+    #[case("0,1,2", 1, "0,1,2")]
+    #[case("0,x", 3, "x")]
+    fn combination_rejects(#[case] query: &str, #[case] column: usize, #[case] bytes: &str) {
+        let error =
+            Combination::parse(query.as_bytes(), query.as_bytes(), &lock(&[10, 10])).unwrap_err();
+
+        assert_eq!(error.column, column);
+        assert_eq!(error.bytes, bytes.as_bytes());
+    }
+
+    #[test]
+    fn a_clockwise_revolution_is_one_step_of_the_next_ring() {
+        let mut lock = lock(&[100, 100]);
+
+        lock.turn(RingInstruction {
+            ring: 1,
+            instruction: Instruction::new(Clockwise, 100),
+        });
+        assert_eq!(lock.combination(), [0, 1]);
+    }
+}
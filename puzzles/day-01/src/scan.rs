@@ -0,0 +1,155 @@
+//! Evaluates the turns of huge inputs on the rayon pool.
+//!
+//! Where a chunk of turns leaves the dial does not depend on where it starts:
+//! it shifts the dial by the same amount from any position, and shifts
+//! compose like addition modulo the size. So a first pass finds the shift of
+//! every chunk in parallel, a prefix sum over those gives the position every
+//! chunk starts at, and a second pass counts the revolutions of every chunk
+//! from there, again in parallel.
+//!
+//! Every turn is parsed twice, so this only beats [`crate::count_zero_passes`]
+//! with more than two threads. In return it keeps none of the parsed turns around,
+//! however big the input.
+
+use aoc_core::ParseError;
+use rayon::prelude::*;
+
+use crate::{Dial, Direction, Instruction, lines};
+
+/// Bytes per chunk, rounded up to the end of a line.
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+/// Turns `dial` like [`Dial::turn`] for every line of `input`, with the same
+/// position and revolutions as doing so one turn at a time.
+pub fn track(dial: Dial, input: &[u8], chunk_size: usize) -> Result<Dial, ParseError> {
+    let chunks = chunks(input, chunk_size);
+
+    let shifts = chunks
+        .par_iter()
+        .map(|chunk| run(dial.at(0), input, chunk))
+        .collect::<Vec<_>>();
+
+    let mut starts = Vec::with_capacity(chunks.len());
+    let mut cursor = dial.at(dial.position);
+    for shift in shifts {
+        starts.push(cursor.position);
+        cursor.turn(Instruction::new(Direction::Clockwise, shift?.position));
+    }
+
+    let dials = chunks
+        .par_iter()
+        .zip(starts)
+        .map(|(chunk, start)| run(dial.at(start), input, chunk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Dial {
+        revolutions: dial.revolutions + dials.iter().map(|dial| dial.revolutions).sum::<usize>(),
+        ..cursor
+    })
+}
+
+/// [`crate::count_zero_passes`] of the puzzle dial, on all threads.
+pub fn count_zero_passes(input: &[u8]) -> Result<usize, ParseError> {
+    track(Dial::new(), input, CHUNK_SIZE).map(|dial| dial.revolutions)
+}
+
+impl Dial {
+    /// A dial of the same size at `position` that has not turned yet.
+    fn at(&self, position: usize) -> Dial {
+        Dial {
+            position,
+            size: self.size,
+            revolutions: 0,
+        }
+    }
+}
+
+fn run(mut dial: Dial, input: &[u8], chunk: &[u8]) -> Result<Dial, ParseError> {
+    for (_, line) in lines(chunk) {
+        dial.turn(Instruction::parse(input, line)?);
+    }
+
+    Ok(dial)
+}
+
+/// Splits `input` after the first newline at least `size` bytes further.
+fn chunks(input: &[u8], size: usize) -> Vec<&[u8]> {
+    let mut chunks = Vec::with_capacity(input.len() / size.max(1) + 1);
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let end = rest[size.min(rest.len())..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(rest.len(), |newline| size.min(rest.len()) + newline + 1);
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::{chunks, track};
+    use crate::{Dial, Direction, Instruction};
+
+    #[rstest]
+    #[case("", 4, &[])]
+    #[case("R1\nL2\n", 0, &["R1\n", "L2\n"])]
+    #[case("R1\nL2\n", 1, &["R1\n", "L2\n"])]
+    #[case("R10\nL2\nR3", 4, &["R10\nL2\n", "R3"])]
+    #[case("R10\nL2\nR3\n", 100, &["R10\nL2\nR3\n"])]
+    fn chunks_end_on_a_line(#[case] input: &str, #[case] size: usize, #[case] expected: &[&str]) {
+        let expected: Vec<&[u8]> = expected.iter().map(|chunk| chunk.as_bytes()).collect();
+
+        assert_eq!(chunks(input.as_bytes(), size), expected);
+    }
+
+    #[test]
+    fn track_reports_the_line_of_an_invalid_turn() {
+        let input = b"R10\nL5\nR3\nL7\nU3\nR1\n";
+
+        let error = track(Dial::new(), input, 3).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 5, 1));
+        assert_eq!(error.bytes, b"U");
+    }
+
+    fn turns() -> impl Strategy<Value = Vec<(bool, usize)>> {
+        proptest::collection::vec((any::<bool>(), 0..=100_000usize), 0..200)
+    }
+
+    fn dial() -> impl Strategy<Value = (usize, usize)> {
+        prop_oneof![1..=300usize, 1..=usize::MAX].prop_flat_map(|size| (Just(size), 0..size))
+    }
+
+    proptest! {
+        #[test]
+        fn track_agrees_with_turning_one_at_a_time(
+            (size, start) in dial(),
+            turns in turns(),
+            chunk_size in 0..=64usize,
+        ) {
+            let dial = || Dial::builder().size(size).start(start).build();
+            let mut input = String::new();
+            let mut expected = dial();
+            for (clockwise, steps) in turns {
+                let (direction, letter) = if clockwise {
+                    (Direction::Clockwise, 'R')
+                } else {
+                    (Direction::CounterClockwise, 'L')
+                };
+                input.push_str(&format!("{letter}{steps}\n"));
+                expected.turn(Instruction::new(direction, steps));
+            }
+
+            let actual = track(dial(), input.as_bytes(), chunk_size).unwrap();
+            prop_assert_eq!(actual.current_position(), expected.current_position());
+            prop_assert_eq!(actual.revolutions(), expected.revolutions());
+        }
+    }
+}
@@ -1,11 +1,30 @@
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day02::IdRanges};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_02_part_02::solve;
+use day_02_part_02::Puzzle;
+
+aoc_core::count_allocations!();
 
 fn bench_solve(c: &mut Criterion) {
-    let input = include_str!("../input.txt").lines().next().unwrap();
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+
+    bench_solution::<Puzzle>(c, "day-02-part-02", &input);
+}
 
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(input))));
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(c, "day-02-part-02", &[8, 16, 32, 64, 128], |count| {
+        IdRanges {
+            count,
+            ..Default::default()
+        }
+        .generate(BENCH_SEED)
+    });
 }
 
 criterion_group!(benches, bench_solve);
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
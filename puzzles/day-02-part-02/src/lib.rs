@@ -1,3 +1,4 @@
+use aoc_core::{ParseError, Solution, parse_number};
 use rayon::prelude::*;
 
 const POWERS_OF_10: [u64; 20] = [
@@ -23,28 +24,57 @@ const POWERS_OF_10: [u64; 20] = [
     10_000_000_000_000_000_000,
 ];
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const PARALLEL: bool = true;
+
+    type Input<'a> = Vec<Range>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_string_of_ranges(input.trim_end()).collect()
+    }
+
+    fn solve(ranges: Self::Input<'_>) -> Self::Output {
+        ranges
+            .into_par_iter()
+            .flat_map(|r| r.into_inner())
+            .filter(|idx| {
+                let digits = calculate_digits(*idx);
+                find_pattern(*idx, digits)
+            })
+            .sum::<u64>()
+    }
+}
+
 pub fn solve<S>(input: &S) -> u64
 where
     S: AsRef<str> + ?Sized,
 {
-    parse_string_of_ranges(input)
-        .par_bridge()
-        .flat_map(|r| r.into_inner())
-        .filter(|idx| {
-            let digits = calculate_digits(*idx);
-            find_pattern(*idx, digits)
-        })
-        .sum::<u64>()
+    Puzzle::run(input.as_ref())
 }
 
-fn parse_string_of_ranges<'a, S>(input: &'a S) -> impl Iterator<Item = Range> + 'a
+pub fn try_solve<S>(input: &S) -> Result<u64, ParseError>
 where
     S: AsRef<str> + ?Sized,
 {
-    input
-        .as_ref()
-        .split(',')
-        .map(|part| part.try_into().unwrap())
+    Puzzle::try_run(input.as_ref())
+}
+
+fn parse_string_of_ranges<'a, S>(
+    input: &'a S,
+) -> impl Iterator<Item = Result<Range, ParseError>> + 'a
+where
+    S: AsRef<str> + ?Sized,
+{
+    let input = input.as_ref();
+
+    input.split(',').map(move |part| {
+        Range::try_from(part).map_err(|e| e.within(input.as_bytes(), part.as_bytes()))
+    })
 }
 
 #[inline]
@@ -85,7 +115,7 @@ fn proper_divisors(n: u32) -> impl Iterator<Item = u32> {
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct Range {
+pub struct Range {
     inner: std::ops::RangeInclusive<u64>,
 }
 
@@ -96,20 +126,24 @@ impl Range {
 }
 
 impl TryFrom<&str> for Range {
-    type Error = String;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (start_str, end_str) = value
-            .split_once('-')
-            .ok_or_else(|| format!("Invalid range format: {}", value))?;
+        let bytes = value.as_bytes();
+        let (start, end) = value.split_once('-').ok_or_else(|| {
+            ParseError::at(Puzzle::DAY, bytes, bytes, "expected a range like `11-22`")
+        })?;
 
-        let start: u64 = start_str
-            .parse()
-            .map_err(|e| format!("Invalid start: {}", e))?;
-        let end: u64 = end_str.parse().map_err(|e| format!("Invalid end: {}", e))?;
+        let start: u64 = parse_number(Puzzle::DAY, bytes, start.as_bytes())?;
+        let end: u64 = parse_number(Puzzle::DAY, bytes, end.as_bytes())?;
 
         if start > end {
-            return Err(format!("Start of range greater than end: {}", value));
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                bytes,
+                bytes,
+                "start of range is greater than its end",
+            ));
         }
 
         Ok(Range {
@@ -120,6 +154,7 @@ impl TryFrom<&str> for Range {
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::ParseError;
     use rstest::rstest;
 
     use crate::{Range, calculate_digits, find_pattern, parse_string_of_ranges, proper_divisors};
@@ -186,7 +221,7 @@ mod tests {
     #[test]
     fn parse_range_works() {
         let range = "11-22";
-        let range: Result<Range, String> = range.try_into();
+        let range: Result<Range, ParseError> = range.try_into();
 
         assert!(range.is_ok());
         let range = range.unwrap();
@@ -201,11 +236,30 @@ mod tests {
     #[test]
     fn parse_ranges_works() {
         let ranges = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let ranges: Vec<Range> = parse_string_of_ranges(ranges).collect();
+        let ranges: Vec<Range> = parse_string_of_ranges(ranges)
+            .collect::<Result<_, _>>()
+            .unwrap();
 
         assert_eq!(ranges.len(), 11);
     }
 
+    #[rstest]
+    #[case("11-22,95-1x5", 1, 10, b"1x5".as_slice())]
+    #[case("11-22,95", 1, 7, b"95".as_slice())]
+    #[case("11-22,\n95-115", 1, 7, b"\n95".as_slice())]
+    #[case("22-11", 1, 1, b"22-11".as_slice())]
+    fn try_solve_reports_offending_range(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+
+        assert_eq!((error.day, error.line, error.column), (2, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
+
     #[test]
     fn example_test_case() {
         let ranges = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
@@ -1,13 +1,64 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+
+    type Input<'a> = Vec<&'a [u8]>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let bytes = input.as_bytes();
+
+        bytes
+            .split(|&b| b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|bank| {
+                if let Some(i) = bank.iter().position(|b| !b.is_ascii_digit()) {
+                    return Err(ParseError::at(
+                        Self::DAY,
+                        bytes,
+                        &bank[i..=i],
+                        "expected a battery joltage digit",
+                    ));
+                }
+
+                if bank.len() < 2 {
+                    return Err(ParseError::at(
+                        Self::DAY,
+                        bytes,
+                        bank,
+                        "bank has too few batteries",
+                    ));
+                }
+
+                Ok(bank)
+            })
+            .collect()
+    }
+
+    fn solve(banks: Self::Input<'_>) -> Self::Output {
+        banks
+            .into_iter()
+            .map(|bank| scan_bank_rtl(bank) as usize)
+            .sum()
+    }
+}
+
 pub fn solve<S>(input: S) -> usize
 where
     S: AsRef<str>,
 {
-    input
-        .as_ref()
-        .as_bytes()
-        .split(|&b| b == b'\n')
-        .map(|line| scan_bank_rtl(line) as usize)
-        .sum()
+    Puzzle::run(input.as_ref())
+}
+
+pub fn try_solve<S>(input: S) -> Result<usize, ParseError>
+where
+    S: AsRef<str>,
+{
+    Puzzle::try_run(input.as_ref())
 }
 
 fn scan_bank_rtl(bank: &[u8]) -> u8 {
@@ -85,4 +136,19 @@ mod tests {
 
         assert_eq!(output, 357)
     }
+
+    #[rstest]
+    #[case("811111111111119\n81111a111111119", 2, 6, b"a".as_slice())]
+    #[case("811111111111119\n9\n", 2, 1, b"9".as_slice())]
+    fn try_solve_rejects_malformed_bank(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+
+        assert_eq!((error.day, error.line, error.column), (3, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
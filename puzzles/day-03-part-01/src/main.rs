@@ -1,11 +1,9 @@
-use std::{fs::read_to_string, time::Instant};
+use std::time::Instant;
 
 use day_03_part_01::solve;
 
 fn main() {
-    let working_dir = std::env::current_dir().unwrap();
-    let path = format!("{}/puzzles/day-03-part-01/input.txt", working_dir.display());
-    let input = read_to_string(path).unwrap();
+    let input = aoc_core::input!(std::env::args_os().nth(1)).unwrap_or_else(|e| panic!("{e}"));
 
     let start = Instant::now();
     let sum = solve(&input);
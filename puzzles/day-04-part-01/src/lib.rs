@@ -1,12 +1,50 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+
+    type Input<'a> = PileOfPaperRolls;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        PileOfPaperRolls::from_str(input.as_bytes())
+    }
+
+    fn solve(pile: Self::Input<'_>) -> Self::Output {
+        pile.count_accessible_rolls()
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let pile = PileOfPaperRolls::from_str(input.as_bytes());
-    pile.count_accessible_rolls()
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
-struct PileOfPaperRolls {
+/// Offsets (dy, dx) of the eight neighbours, shifted by one so they stay unsigned.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const NEIGHBOURS: [(usize, usize); 8] = [
+    (0, 0),
+    (0, 1),
+    (0, 2),
+    (1, 0),
+    (1, 2),
+    (2, 0),
+    (2, 1),
+    (2, 2),
+];
+
+pub struct PileOfPaperRolls {
     grid: Vec<u8>,
     width: usize,
     height: usize,
@@ -15,41 +53,82 @@ struct PileOfPaperRolls {
 
 impl PileOfPaperRolls {
     const SIMD_WIDTH: usize = 32;
-    const PROC_CELLS: usize = Self::SIMD_WIDTH - 2;
-    const VALIDITY_CHECK: u32 = 0x3FFFFFFF; // bits 0-29 (30 cells); bit 30-31 niet meegenomen voor rechter boundary
+    // elke load bekijkt 32 of 16 cellen, de buitenste twee zijn alleen buren
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    const AVX2_CELLS: usize = 32 - 2;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    const SSE2_CELLS: usize = 16 - 2;
 
-    fn from_str(grid: &[u8]) -> Self {
+    fn from_str(grid: &[u8]) -> Result<Self, ParseError> {
         let width = grid.iter().position(|&b| b == b'\n').unwrap_or(grid.len());
 
         let stride = width + 2;
 
         let padded_width = (stride + Self::SIMD_WIDTH - 1) & !(Self::SIMD_WIDTH - 1);
 
-        let height = grid.len() / (width + 1); // +1 for newline
+        let rows = grid
+            .split(|&b| b == b'\n')
+            .take_while(|line| !line.is_empty());
+        let height = rows.clone().count();
         let padded_height = height + 2;
 
-        let mut data = vec![b'.'; padded_width * padded_height];
-
-        for (y, line) in grid.split(|&b| b == b'\n').enumerate() {
-            // if eof line:
-            if line.is_empty() {
-                break;
+        // extra SIMD_WIDTH zodat de laatste unaligned loads binnen de buffer blijven
+        let mut data = vec![b'.'; padded_width * padded_height + Self::SIMD_WIDTH];
+
+        for (y, line) in rows.enumerate() {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    grid,
+                    line,
+                    "row is not as wide as the first row",
+                ));
+            }
+            if let Some(x) = line.iter().position(|&b| b != b'.' && b != b'@') {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    grid,
+                    &line[x..=x],
+                    "expected `.` or `@`",
+                ));
             }
+
             let start = (y + 1) * padded_width + 1; // +1 to skip the left and top padding
-            let _ = &mut data[start..start + width].copy_from_slice(line);
+            data[start..start + width].copy_from_slice(line);
         }
 
-        PileOfPaperRolls {
+        Ok(Self {
             grid: data,
             width,
             height,
             padded_width,
-        }
+        })
     }
 
     // Only rolls that have fewer than 4 adjacent '@'s are considered accessible
     fn count_accessible_rolls(&self) -> usize {
-        unsafe { self.simd_convolution(b'@', b'@', 4) }
+        self.convolution(b'@', b'@', 4)
+    }
+
+    /// Picks the widest implementation the CPU supports at runtime, so the
+    /// binary does not depend on `target-cpu=native`. Rows narrower than one
+    /// load are counted by the scalar version.
+    fn convolution(&self, center_value: u8, neighbour_value: u8, max_neighbours: usize) -> usize {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if self.width >= Self::AVX2_CELLS && is_x86_feature_detected!("avx2") {
+                return unsafe {
+                    self.avx2_convolution(center_value, neighbour_value, max_neighbours)
+                };
+            }
+            if self.width >= Self::SSE2_CELLS && is_x86_feature_detected!("sse2") {
+                return unsafe {
+                    self.sse2_convolution(center_value, neighbour_value, max_neighbours)
+                };
+            }
+        }
+
+        self.scalar_convolution(center_value, neighbour_value, max_neighbours)
     }
 
     fn scalar_convolution(
@@ -89,200 +168,101 @@ impl PileOfPaperRolls {
         count
     }
 
-    unsafe fn simd_convolution(
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn avx2_convolution(
         &self,
         center_value: u8,
         neighbour_value: u8,
         max_neighbours: usize,
     ) -> usize {
-        if self.width < Self::PROC_CELLS {
-            return self.scalar_convolution(center_value, neighbour_value, max_neighbours);
-        }
+        const CELLS: usize = PileOfPaperRolls::AVX2_CELLS;
+
+        let ptr = self.grid.as_ptr();
+        let neighbour_mask = _mm256_set1_epi8(neighbour_value as i8);
+        let one_mask = _mm256_set1_epi8(1);
+        let max_neighbours_mask = _mm256_set1_epi8(max_neighbours as i8);
+        let center_mask = _mm256_set1_epi8(center_value as i8);
 
         let mut count = 0;
 
         for y in 1..=self.height {
             let mut x = 1;
-            while x + Self::PROC_CELLS <= self.width {
-                unsafe {
-                    let ptr = self.grid.as_ptr();
-
-                    // todo: kijken of ik _mm256_slli_si256 en _mm256_srli_si256 kan gebruiken om te byte shiften in plaats van 3 loads te doen per rij
-
-                    // laad de registers uit de grid
-                    let above_left = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + x - 1) as *const __m256i,
-                    );
-                    let above_center = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + x) as *const __m256i
-                    );
-                    let above_right = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + x + 1) as *const __m256i,
-                    );
-                    let center_left = _mm256_loadu_si256(
-                        ptr.add(y * self.padded_width + x - 1) as *const __m256i
-                    );
-                    let center_mid =
-                        _mm256_loadu_si256(ptr.add(y * self.padded_width + x) as *const __m256i);
-                    let center_right = _mm256_loadu_si256(
-                        ptr.add(y * self.padded_width + x + 1) as *const __m256i
-                    );
-                    let below_left = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + x - 1) as *const __m256i,
-                    );
-                    let below_center = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + x) as *const __m256i
-                    );
-                    let below_right = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + x + 1) as *const __m256i,
-                    );
-
-                    // dit stelt een register met 16 bytes in op de waarde van neighbour_value
-                    let neighbour_mask = _mm256_set1_epi8(neighbour_value as i8);
-
-                    // vergelijk
-                    let above_left_eq = _mm256_cmpeq_epi8(above_left, neighbour_mask);
-                    let above_center_eq = _mm256_cmpeq_epi8(above_center, neighbour_mask);
-                    let above_right_eq = _mm256_cmpeq_epi8(above_right, neighbour_mask);
-                    let center_left_eq = _mm256_cmpeq_epi8(center_left, neighbour_mask);
-                    let center_right_eq = _mm256_cmpeq_epi8(center_right, neighbour_mask);
-                    let below_left_eq = _mm256_cmpeq_epi8(below_left, neighbour_mask);
-                    let below_center_eq = _mm256_cmpeq_epi8(below_center, neighbour_mask);
-                    let below_right_eq = _mm256_cmpeq_epi8(below_right, neighbour_mask);
-
-                    // maak een mask met alle bits op 1
-                    let one_mask = _mm256_set1_epi8(1);
-
-                    // gebruik bitwise and om de bits te pakken
-                    // nu hebben de we hoeveelheid neighbours per cel
-                    let above_left_bit = _mm256_and_si256(above_left_eq, one_mask);
-                    let above_center_bit = _mm256_and_si256(above_center_eq, one_mask);
-                    let above_right_bit = _mm256_and_si256(above_right_eq, one_mask);
-                    let center_left_bit = _mm256_and_si256(center_left_eq, one_mask);
-                    let center_right_bit = _mm256_and_si256(center_right_eq, one_mask);
-                    let below_left_bit = _mm256_and_si256(below_left_eq, one_mask);
-                    let below_center_bit = _mm256_and_si256(below_center_eq, one_mask);
-                    let below_right_bit = _mm256_and_si256(below_right_eq, one_mask);
-
-                    // tel alles op
-                    let sum = _mm256_add_epi8(above_left_bit, above_center_bit);
-                    let sum = _mm256_add_epi8(sum, above_right_bit);
-                    let sum = _mm256_add_epi8(sum, center_left_bit);
-                    let sum = _mm256_add_epi8(sum, center_right_bit);
-                    let sum = _mm256_add_epi8(sum, below_left_bit);
-                    let sum = _mm256_add_epi8(sum, below_center_bit);
-                    let sum = _mm256_add_epi8(sum, below_right_bit);
-
-                    // register met de max waarde
-                    let max_neighbours_mask = _mm256_set1_epi8(max_neighbours as i8);
-                    let neighbours_threshold = _mm256_cmpgt_epi8(max_neighbours_mask, sum); // max_neighbours > sum van neighbours
-
-                    // check de center cel zelf ook op de juiste waarde
-                    let center_mask = _mm256_set1_epi8(center_value as i8);
-                    let center_matches = _mm256_cmpeq_epi8(center_mid, center_mask);
-
-                    // combineer de masks via bitwise and
-                    let result = _mm256_and_si256(neighbours_threshold, center_matches);
-                    let mask = _mm256_movemask_epi8(result) as u32;
-                    let matches = (mask & Self::VALIDITY_CHECK).count_ones() as usize;
-                    count += matches;
+            while x <= self.width {
+                // de laatste stap per rij kan minder dan CELLS cellen bevatten
+                let cells = CELLS.min(self.width - x + 1);
+                let mut sum = _mm256_setzero_si256();
+
+                for (dy, dx) in NEIGHBOURS {
+                    // laad, vergelijk en tel op
+                    let offset = (y + dy - 1) * self.padded_width + x + dx - 1;
+                    let neighbours = unsafe { _mm256_loadu_si256(ptr.add(offset).cast()) };
+                    let eq = _mm256_cmpeq_epi8(neighbours, neighbour_mask);
+                    sum = _mm256_add_epi8(sum, _mm256_and_si256(eq, one_mask));
                 }
 
-                x += Self::PROC_CELLS;
+                let center =
+                    unsafe { _mm256_loadu_si256(ptr.add(y * self.padded_width + x).cast()) };
+                let center_matches = _mm256_cmpeq_epi8(center, center_mask);
+                let neighbours_threshold = _mm256_cmpgt_epi8(max_neighbours_mask, sum); // max_neighbours > sum van neighbours
+
+                let result = _mm256_and_si256(neighbours_threshold, center_matches);
+                let mask = _mm256_movemask_epi8(result) as u32;
+                count += (mask & ((1u32 << cells) - 1)).count_ones() as usize;
+
+                x += CELLS;
             }
+        }
+
+        count
+    }
+
+    /// # Safety
+    ///
+    /// The CPU must support SSE2.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[target_feature(enable = "sse2")]
+    unsafe fn sse2_convolution(
+        &self,
+        center_value: u8,
+        neighbour_value: u8,
+        max_neighbours: usize,
+    ) -> usize {
+        const CELLS: usize = PileOfPaperRolls::SSE2_CELLS;
+
+        let ptr = self.grid.as_ptr();
+        let neighbour_mask = _mm_set1_epi8(neighbour_value as i8);
+        let one_mask = _mm_set1_epi8(1);
+        let max_neighbours_mask = _mm_set1_epi8(max_neighbours as i8);
+        let center_mask = _mm_set1_epi8(center_value as i8);
+
+        let mut count = 0;
 
-            if x <= self.width {
-                // masked load because we might have less than PROC_CELLS remaining
-
-                let remaining = self.width - x + 1;
-                let final_x = x;
-
-                unsafe {
-                    let ptr = self.grid.as_ptr();
-
-                    // todo: kijken of ik _mm256_slli_si256 en _mm256_srli_si256 kan gebruiken om te byte shiften in plaats van 3 loads te doen per rij
-
-                    // laad de registers uit de grid
-                    let above_left = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + final_x - 1) as *const __m256i,
-                    );
-                    let above_center = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + final_x) as *const __m256i,
-                    );
-                    let above_right = _mm256_loadu_si256(
-                        ptr.add((y - 1) * self.padded_width + final_x + 1) as *const __m256i,
-                    );
-                    let center_left = _mm256_loadu_si256(
-                        ptr.add(y * self.padded_width + final_x - 1) as *const __m256i,
-                    );
-                    let center_mid = _mm256_loadu_si256(
-                        ptr.add(y * self.padded_width + final_x) as *const __m256i
-                    );
-                    let center_right = _mm256_loadu_si256(
-                        ptr.add(y * self.padded_width + final_x + 1) as *const __m256i,
-                    );
-                    let below_left = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + final_x - 1) as *const __m256i,
-                    );
-                    let below_center = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + final_x) as *const __m256i,
-                    );
-                    let below_right = _mm256_loadu_si256(
-                        ptr.add((y + 1) * self.padded_width + final_x + 1) as *const __m256i,
-                    );
-
-                    // dit stelt een register met 16 bytes in op de waarde van neighbour_value
-                    let neighbour_mask = _mm256_set1_epi8(neighbour_value as i8);
-
-                    // vergelijk
-                    let above_left_eq = _mm256_cmpeq_epi8(above_left, neighbour_mask);
-                    let above_center_eq = _mm256_cmpeq_epi8(above_center, neighbour_mask);
-                    let above_right_eq = _mm256_cmpeq_epi8(above_right, neighbour_mask);
-                    let center_left_eq = _mm256_cmpeq_epi8(center_left, neighbour_mask);
-                    let center_right_eq = _mm256_cmpeq_epi8(center_right, neighbour_mask);
-                    let below_left_eq = _mm256_cmpeq_epi8(below_left, neighbour_mask);
-                    let below_center_eq = _mm256_cmpeq_epi8(below_center, neighbour_mask);
-                    let below_right_eq = _mm256_cmpeq_epi8(below_right, neighbour_mask);
-
-                    // maak een mask met alle bits op 1
-                    let one_mask = _mm256_set1_epi8(1);
-
-                    // gebruik bitwise and om de bits te pakken
-                    // nu hebben de we hoeveelheid neighbours per cel
-                    let above_left_bit = _mm256_and_si256(above_left_eq, one_mask);
-                    let above_center_bit = _mm256_and_si256(above_center_eq, one_mask);
-                    let above_right_bit = _mm256_and_si256(above_right_eq, one_mask);
-                    let center_left_bit = _mm256_and_si256(center_left_eq, one_mask);
-                    let center_right_bit = _mm256_and_si256(center_right_eq, one_mask);
-                    let below_left_bit = _mm256_and_si256(below_left_eq, one_mask);
-                    let below_center_bit = _mm256_and_si256(below_center_eq, one_mask);
-                    let below_right_bit = _mm256_and_si256(below_right_eq, one_mask);
-
-                    // tel alles op
-                    let sum = _mm256_add_epi8(above_left_bit, above_center_bit);
-                    let sum = _mm256_add_epi8(sum, above_right_bit);
-                    let sum = _mm256_add_epi8(sum, center_left_bit);
-                    let sum = _mm256_add_epi8(sum, center_right_bit);
-                    let sum = _mm256_add_epi8(sum, below_left_bit);
-                    let sum = _mm256_add_epi8(sum, below_center_bit);
-                    let sum = _mm256_add_epi8(sum, below_right_bit);
-
-                    // register met de max waarde
-                    let max_neighbours_mask = _mm256_set1_epi8(max_neighbours as i8);
-                    let neighbours_threshold = _mm256_cmpgt_epi8(max_neighbours_mask, sum); // max_neighbours > sum van neighbours
-
-                    // check de center cel zelf ook op de juiste waarde
-                    let center_mask = _mm256_set1_epi8(center_value as i8);
-                    let center_matches = _mm256_cmpeq_epi8(center_mid, center_mask);
-
-                    // combineer de masks via bitwise and
-                    let result = _mm256_and_si256(neighbours_threshold, center_matches);
-                    let mask = _mm256_movemask_epi8(result) as u32;
-                    let remainder_mask = (1u32 << remaining) - 1;
-                    let matches = (mask & remainder_mask).count_ones() as usize;
-
-                    count += matches;
+        for y in 1..=self.height {
+            let mut x = 1;
+            while x <= self.width {
+                let cells = CELLS.min(self.width - x + 1);
+                let mut sum = _mm_setzero_si128();
+
+                for (dy, dx) in NEIGHBOURS {
+                    let offset = (y + dy - 1) * self.padded_width + x + dx - 1;
+                    let neighbours = unsafe { _mm_loadu_si128(ptr.add(offset).cast()) };
+                    let eq = _mm_cmpeq_epi8(neighbours, neighbour_mask);
+                    sum = _mm_add_epi8(sum, _mm_and_si128(eq, one_mask));
                 }
+
+                let center = unsafe { _mm_loadu_si128(ptr.add(y * self.padded_width + x).cast()) };
+                let center_matches = _mm_cmpeq_epi8(center, center_mask);
+                let neighbours_threshold = _mm_cmpgt_epi8(max_neighbours_mask, sum);
+
+                let result = _mm_and_si128(neighbours_threshold, center_matches);
+                let mask = _mm_movemask_epi8(result) as u32;
+                count += (mask & ((1u32 << cells) - 1)).count_ones() as usize;
+
+                x += CELLS;
             }
         }
 
@@ -299,17 +279,46 @@ mod tests {
         let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n";
         let expected = 13;
 
-        let pile = PileOfPaperRolls::from_str(input.as_bytes());
+        let pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
         let result = pile.scalar_convolution(b'@', b'@', 4);
         assert_eq!(result, expected);
     }
 
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    proptest::proptest! {
+        #[test]
+        fn simd_paths_agree_with_scalar(rows in aoc_core::differential::grid(1..=12, b".@")) {
+            let input = aoc_core::differential::to_input(&rows);
+            let pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
+            let expected = pile.scalar_convolution(b'@', b'@', 4);
+
+            if is_x86_feature_detected!("avx2") {
+                proptest::prop_assert_eq!(unsafe { pile.avx2_convolution(b'@', b'@', 4) }, expected);
+            }
+            proptest::prop_assert_eq!(unsafe { pile.sse2_convolution(b'@', b'@', 4) }, expected);
+        }
+    }
+
     #[test]
     fn pile_from_str() {
         let input = "..@..\n.@@@.\n..@..\n";
-        let pile = PileOfPaperRolls::from_str(input.as_bytes());
+        let pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
         assert_eq!(pile.width, 5);
         assert_eq!(pile.height, 3);
         assert_eq!(pile.padded_width, 32);
     }
+
+    #[test]
+    fn try_solve_rejects_jagged_rows() {
+        let error = try_solve("..@..\n.@@@\n..@..\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (4, 2, 1));
+        assert_eq!(error.bytes, b".@@@");
+    }
+
+    #[test]
+    fn try_solve_rejects_unknown_cell() {
+        let error = try_solve("..@..\n.@#@.\n..@..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.bytes, b"#");
+    }
 }
@@ -1,12 +1,34 @@
+use aoc_core::{ParseError, Solution};
 use wide::{CmpEq, CmpGt, i8x32};
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+
+    type Input<'a> = PileOfPaperRolls;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        PileOfPaperRolls::from_str(input.as_bytes())
+    }
+
+    fn solve(mut pile: Self::Input<'_>) -> Self::Output {
+        let params = ConvolutionParams {
+            char: b'@',
+            max_neighbours: 4,
+        };
+        pile.remove_all_accessible(&params)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let mut pile = PileOfPaperRolls::from_str(input.as_bytes());
-    let params = ConvolutionParams {
-        char: b'@',
-        max_neighbours: 4,
-    };
-    pile.remove_all_accessible(&params)
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
 #[derive(Clone, Copy)]
@@ -15,7 +37,7 @@ struct ConvolutionParams {
     max_neighbours: usize,
 }
 
-struct PileOfPaperRolls {
+pub struct PileOfPaperRolls {
     grid: Vec<u8>,
     width: usize,
     height: usize,
@@ -27,28 +49,50 @@ impl PileOfPaperRolls {
     const PROC_CELLS: usize = Self::SIMD_WIDTH - 2;
     const VALIDITY_CHECK: u32 = 0x3FFF_FFFF;
 
-    fn from_str(grid: &[u8]) -> Self {
+    fn from_str(grid: &[u8]) -> Result<Self, ParseError> {
         let width = grid.iter().position(|&b| b == b'\n').unwrap_or(grid.len());
+
         let stride = width + 2;
-        let padded_width = (stride + Self::SIMD_WIDTH - 1) & !(Self::SIMD_WIDTH - 1);
-        let height = grid.len() / (width + 1);
 
-        let mut data = vec![b'.'; padded_width * (height + 2)];
+        let padded_width = (stride + Self::SIMD_WIDTH - 1) & !(Self::SIMD_WIDTH - 1);
 
-        for (y, line) in grid.split(|&b| b == b'\n').enumerate() {
-            if line.is_empty() {
-                break;
+        let rows = grid
+            .split(|&b| b == b'\n')
+            .take_while(|line| !line.is_empty());
+        let height = rows.clone().count();
+        let padded_height = height + 2;
+
+        // extra SIMD_WIDTH zodat de laatste unaligned loads binnen de buffer blijven
+        let mut data = vec![b'.'; padded_width * padded_height + Self::SIMD_WIDTH];
+
+        for (y, line) in rows.enumerate() {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    grid,
+                    line,
+                    "row is not as wide as the first row",
+                ));
             }
-            let start = (y + 1) * padded_width + 1;
+            if let Some(x) = line.iter().position(|&b| b != b'.' && b != b'@') {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    grid,
+                    &line[x..=x],
+                    "expected `.` or `@`",
+                ));
+            }
+
+            let start = (y + 1) * padded_width + 1; // +1 to skip the left and top padding
             data[start..start + width].copy_from_slice(line);
         }
 
-        Self {
+        Ok(Self {
             grid: data,
             width,
             height,
             padded_width,
-        }
+        })
     }
 
     #[inline(always)]
@@ -219,7 +263,7 @@ mod tests {
     #[case(8, 1)]
     #[case(9, 1)]
     fn removal_round(#[case] round: usize, #[case] expected_removed: usize) {
-        let mut pile = PileOfPaperRolls::from_str(EXAMPLE.as_bytes());
+        let mut pile = PileOfPaperRolls::from_str(EXAMPLE.as_bytes()).unwrap();
         let params = ConvolutionParams {
             char: b'@',
             max_neighbours: 4,
@@ -234,7 +278,7 @@ mod tests {
     #[rstest]
     #[case(EXAMPLE, 43)]
     fn remove_all(#[case] input: &str, #[case] expected_total: usize) {
-        let mut pile = PileOfPaperRolls::from_str(input.as_bytes());
+        let mut pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
         let params = ConvolutionParams {
             char: b'@',
             max_neighbours: 4,
@@ -245,8 +289,8 @@ mod tests {
     #[test]
     fn simd_matches_scalar_removal() {
         let input = read_to_string("input.txt").unwrap();
-        let mut scalar_pile = PileOfPaperRolls::from_str(input.as_bytes());
-        let mut simd_pile = PileOfPaperRolls::from_str(input.as_bytes());
+        let mut scalar_pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
+        let mut simd_pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
         let params = ConvolutionParams {
             char: b'@',
             max_neighbours: 4,
@@ -268,17 +312,58 @@ mod tests {
         assert_eq!(scalar_pile.grid, simd_pile.grid);
     }
 
+    proptest::proptest! {
+        // de volgorde van verwijderen verschilt per ronde, het eindresultaat niet
+        #[test]
+        fn simd_removal_agrees_with_scalar(rows in aoc_core::differential::grid(1..=12, b".@")) {
+            let input = aoc_core::differential::to_input(&rows);
+            let mut scalar_pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
+            let mut simd_pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
+            let params = ConvolutionParams {
+                char: b'@',
+                max_neighbours: 4,
+            };
+
+            let mut scalar_total = 0;
+            loop {
+                let removed = scalar_pile.scalar_remove_accessible(&params);
+                if removed == 0 {
+                    break;
+                }
+                scalar_total += removed;
+            }
+
+            proptest::prop_assert_eq!(simd_pile.remove_all_accessible(&params), scalar_total);
+            proptest::prop_assert_eq!(scalar_pile.grid, simd_pile.grid);
+        }
+    }
+
     #[rstest]
     #[case("..@..\n.@@@.\n..@..\n", 5, 3, 32)]
+    #[case("..@..\n.@@@.\n..@..", 5, 3, 32)]
     fn pile_from_str(
         #[case] input: &str,
         #[case] width: usize,
         #[case] height: usize,
         #[case] padded_width: usize,
     ) {
-        let pile = PileOfPaperRolls::from_str(input.as_bytes());
+        let pile = PileOfPaperRolls::from_str(input.as_bytes()).unwrap();
         assert_eq!(pile.width, width);
         assert_eq!(pile.height, height);
         assert_eq!(pile.padded_width, padded_width);
     }
+
+    #[rstest]
+    #[case("..@..\n.@@@\n..@..\n", 2, 1, b".@@@".as_slice())]
+    #[case("..@..\n.@#@.\n..@..\n", 2, 3, b"#".as_slice())]
+    fn try_solve_rejects_malformed_grid(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (4, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
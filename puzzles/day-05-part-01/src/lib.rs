@@ -1,41 +1,72 @@
+use aoc_core::{ParseError, Solution, parse_number};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
+
+    type Input<'a> = Inventory;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let bytes = input.as_bytes();
+
+        // ranges en ids worden gescheiden door een lege regel
+        let (range_section, id_section) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::eof(Self::DAY, bytes, "expected a blank line before the ids")
+        })?;
+
+        let ranges = range_section
+            .as_bytes()
+            .split(|&c| c == b'\n')
+            .map(|line| parse_range(bytes, line))
+            .collect::<Result<Vec<(usize, usize)>, ParseError>>()?;
+        let ids = id_section
+            .as_bytes()
+            .split(|&c| c == b'\n')
+            .take_while(|line| !line.is_empty())
+            .map(|line| parse_number(Self::DAY, bytes, line))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        Ok(Inventory { ranges, ids })
+    }
+
+    fn solve(inventory: Self::Input<'_>) -> Self::Output {
+        let Inventory { ranges, ids } = inventory;
+
+        ids.iter()
+            .filter(|&&id| ranges.iter().any(|&(start, end)| id >= start && id <= end))
+            .count()
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    // create vec of lines
-    let lines = input
-        .as_bytes()
-        .split(|&c| c == b'\n')
-        .collect::<Vec<&[u8]>>();
-
-    // create 2 vectors: one for the ranges and one for the ids
-    let mut sections = lines.split(|line| line.is_empty());
-    let ranges = sections
-        .next()
-        .unwrap()
-        .iter() // todo: parallelize
-        .map(|line| {
-            let mut parts = line.split(|&c| c == b'-');
-            let start = unsafe { std::str::from_utf8_unchecked(parts.next().unwrap()) }
-                .parse()
-                .unwrap();
-            let end = unsafe { std::str::from_utf8_unchecked(parts.next().unwrap()) }
-                .parse()
-                .unwrap();
-            (start, end)
-        })
-        .collect::<Vec<(usize, usize)>>();
-    let ids = sections
-        .next()
-        .unwrap()
-        .iter() // todo parallelize
-        .map(|line| {
-            unsafe { std::str::from_utf8_unchecked(line) }
-                .parse::<usize>()
-                .unwrap()
-        })
-        .collect::<Vec<usize>>();
-
-    ids.iter()
-        .filter(|&&id| ranges.iter().any(|&(start, end)| id >= start && id <= end))
-        .count()
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+fn parse_range(input: &[u8], line: &[u8]) -> Result<(usize, usize), ParseError> {
+    let Some(dash) = line.iter().position(|&c| c == b'-') else {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            input,
+            line,
+            "expected a range like `3-5`",
+        ));
+    };
+
+    let start = parse_number(Puzzle::DAY, input, &line[..dash])?;
+    let end = parse_number(Puzzle::DAY, input, &line[dash + 1..])?;
+    Ok((start, end))
+}
+
+pub struct Inventory {
+    ranges: Vec<(usize, usize)>,
+    ids: Vec<usize>,
 }
 
 #[cfg(test)]
@@ -48,4 +79,29 @@ mod tests {
         let answer = super::solve(&input);
         assert_eq!(answer, 3);
     }
+
+    #[test]
+    fn try_solve_reports_missing_separator() {
+        let error = super::try_solve("3-5\n10-14\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (5, 3, 1));
+        assert!(error.bytes.is_empty());
+    }
+
+    #[test]
+    fn try_solve_reports_invalid_range() {
+        let error = super::try_solve("3-5\n10+14\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.bytes, b"10+14");
+
+        let error = super::try_solve("3-5\n10-1a4\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.bytes, b"1a4");
+    }
+
+    #[test]
+    fn try_solve_reports_invalid_id() {
+        let error = super::try_solve("3-5\n\n1\n-8\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.bytes, b"-8");
+    }
 }
@@ -1,36 +1,68 @@
 #![feature(slice_split_once)]
 
+use aoc_core::{ParseError, Solution, parse_number};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const PART: u8 = 2;
+
+    type Input<'a> = Vec<(usize, usize)>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve(ranges: Self::Input<'_>) -> Self::Output {
+        let consolidated = consolidate(ranges);
+        sum_ranges(&consolidated)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let ranges = parse_input(input);
-    let consolidated = consolidate(ranges);
-    sum_ranges(&consolidated)
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
 #[inline]
-fn parse_input(input: &str) -> Vec<(usize, usize)> {
-    input
-        .as_bytes()
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let bytes = input.as_bytes();
+
+    let ranges = bytes
         .split(|&c| c == b'\n')
         .take_while(|line| !line.is_empty())
         .map(|line| {
-            let parts = line.split_once(|&c| c == b'-').unwrap();
+            let parts = line.split_once(|&c| c == b'-').ok_or_else(|| {
+                ParseError::at(Puzzle::DAY, bytes, line, "expected a range like `3-5`")
+            })?;
 
-            let start = unsafe { std::str::from_utf8_unchecked(parts.0) }
-                .parse()
-                .unwrap();
+            let start = parse_number(Puzzle::DAY, bytes, parts.0)?;
+            let end = parse_number(Puzzle::DAY, bytes, parts.1)?;
 
-            let end = unsafe { std::str::from_utf8_unchecked(parts.1) }
-                .parse()
-                .unwrap();
-
-            (start, end)
+            Ok((start, end))
         })
-        .collect::<Vec<(usize, usize)>>()
+        .collect::<Result<Vec<(usize, usize)>, ParseError>>()?;
+
+    if ranges.is_empty() {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            bytes,
+            &bytes[..0],
+            "expected at least one range",
+        ));
+    }
+
+    Ok(ranges)
 }
 
 #[inline]
 fn consolidate(mut ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
-    ranges.sort_by(|&a, &b| a.0.cmp(&b.0));
+    ranges.sort_by_key(|&(start, _)| start);
 
     let mut consolidated: Vec<(usize, usize)> = Vec::new();
     consolidated.push(unsafe { *ranges.get_unchecked(0) });
@@ -49,7 +81,7 @@ fn consolidate(mut ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
 }
 
 #[inline]
-fn sum_ranges(ranges: &Vec<(usize, usize)>) -> usize {
+fn sum_ranges(ranges: &[(usize, usize)]) -> usize {
     ranges.iter().map(|&(start, end)| end - start + 1).sum()
 }
 
@@ -101,7 +133,22 @@ mod tests {
     fn parse_input() {
         let input = read_to_string("example.txt").unwrap();
         let expected = vec![(3, 5), (10, 14), (16, 20), (12, 18)];
-        let result = super::parse_input(&input);
+        let result = super::parse_input(&input).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case("3-5\n10_14\n\n1\n", 2, 1, b"10_14".as_slice())]
+    #[case("3-5\n10-\n\n1\n", 2, 4, b"".as_slice())]
+    #[case("\n1\n", 1, 1, b"".as_slice())]
+    fn try_solve_rejects_malformed_ranges(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (5, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
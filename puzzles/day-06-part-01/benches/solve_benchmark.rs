@@ -1,16 +1,34 @@
-use std::fs::read_to_string;
-
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day06::Worksheet};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_06_part_01::solve;
+use day_06_part_01::Puzzle;
+
+aoc_core::count_allocations!();
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    c.bench_function("solve", |b| {
-        b.iter(|| solve(std::hint::black_box(input.as_bytes())))
-    });
-    let answer = solve(input.as_bytes());
-    assert_eq!(answer, 6605396225322);
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-06-part-01", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(
+        c,
+        "day-06-part-01",
+        &[100, 1_000, 10_000, 100_000],
+        |problems| {
+            Worksheet {
+                problems,
+                ..Default::default()
+            }
+            .generate(BENCH_SEED)
+        },
+    );
 }
 
 criterion_group!(benches, bench_solve);
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
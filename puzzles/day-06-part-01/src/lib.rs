@@ -1,39 +1,71 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+
+    type Input<'a> = CephalopodMathWorksheet;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        CephalopodMathWorksheet::from_slice(input.as_bytes())
+    }
+
+    fn solve(matrix: Self::Input<'_>) -> Self::Output {
+        (0..matrix.columns)
+            .map(|c| matrix.apply_operator_to_column(c as usize))
+            .sum()
+    }
+}
+
 pub fn solve(input: &[u8]) -> u64 {
-    let matrix = CephalopodMathWorksheet::from_slice(input);
+    try_solve(input).unwrap_or_else(|e| panic!("{e}"))
+}
 
-    (0..matrix.columns)
-        .map(|c| matrix.apply_operator_to_column(c as usize))
-        .sum()
+pub fn try_solve(input: &[u8]) -> Result<u64, ParseError> {
+    Puzzle::try_run(aoc_core::from_utf8(Puzzle::DAY, input)?)
 }
 
 #[derive(Debug)]
-struct CephalopodMathWorksheet {
+pub struct CephalopodMathWorksheet {
     flattened: Vec<u64>,
     columns: u64,
     rows: u64,
     operators: Vec<Operator>,
 }
 
-#[derive(Debug)]
-enum Operator {
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
     Add,
     Multiply,
 }
 
 impl CephalopodMathWorksheet {
-    fn from_slice(input: &[u8]) -> Self {
-        let width = input.iter().position(|&c| c == b'\n').unwrap();
+    fn from_slice(input: &[u8]) -> Result<Self, ParseError> {
+        let width = input
+            .iter()
+            .position(|&c| c == b'\n')
+            .ok_or_else(|| ParseError::eof(Puzzle::DAY, input, "expected a row of numbers"))?;
         let stride = width + 1; // including newline
-        let cols = unsafe { std::str::from_utf8_unchecked(&input[..width]) }
-            .split_ascii_whitespace()
+        check_rows(input, width)?;
+
+        let cols = input[..width]
+            .split(|&c| c == b' ')
+            .filter(|number| !number.is_empty())
             .count();
         let rows = input.len() / stride;
-
-        let mut flattened: Vec<u64> = Vec::with_capacity(rows * cols);
-        unsafe {
-            flattened.set_len(rows * cols);
+        if rows < 2 {
+            return Err(ParseError::eof(
+                Puzzle::DAY,
+                input,
+                "expected a row of operators",
+            ));
         }
 
+        let mut flattened: Vec<u64> = vec![0; rows * cols];
+
         // iterate over every byte, and incrementally parse numbers
         let mut row = 0;
         let mut col = 0;
@@ -48,6 +80,16 @@ impl CephalopodMathWorksheet {
                 }
                 b' ' | b'\n' => {
                     if i > 0 && input[i - 1] != b' ' && input[i - 1] != b'\n' {
+                        if col == cols {
+                            let line = &input[row * stride..row * stride + width];
+                            return Err(ParseError::at(
+                                Puzzle::DAY,
+                                input,
+                                line,
+                                "row has more numbers than the first row",
+                            ));
+                        }
+
                         let index = col * rows + row;
                         unsafe {
                             *flattened.get_unchecked_mut(index) = current_number;
@@ -61,18 +103,31 @@ impl CephalopodMathWorksheet {
                         col = 0;
                     }
                 }
-                b => panic!("Ongeldige input: {:?}", b),
+                _ => {
+                    return Err(ParseError::at(
+                        Puzzle::DAY,
+                        input,
+                        &input[i..=i],
+                        "expected a digit or a space",
+                    ));
+                }
             }
         }
 
         // the last row should be its own vector parsed to operators
-        let mut operators_per_column: Vec<Operator> = Vec::with_capacity(cols);
-        unsafe {
-            operators_per_column.set_len(cols);
-        }
+        let mut operators_per_column: Vec<Operator> = vec![Operator::Add; cols];
         let start_of_last_row = (rows - 1) * stride;
         let mut current_col = 0;
-        for &byte in input[start_of_last_row..].iter() {
+        for (i, &byte) in input.iter().enumerate().skip(start_of_last_row) {
+            if matches!(byte, b'+' | b'*') && current_col == cols {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    input,
+                    &input[i..=i],
+                    "more operators than columns",
+                ));
+            }
+
             match byte {
                 b'+' => {
                     unsafe {
@@ -87,16 +142,23 @@ impl CephalopodMathWorksheet {
                     current_col += 1;
                 }
                 b' ' | b'\n' => {}
-                b => panic!("Ongeldige operator: {:?}", b),
+                _ => {
+                    return Err(ParseError::at(
+                        Puzzle::DAY,
+                        input,
+                        &input[i..=i],
+                        "expected `+` or `*`",
+                    ));
+                }
             }
         }
 
-        CephalopodMathWorksheet {
+        Ok(CephalopodMathWorksheet {
             flattened,
             columns: cols as u64,
             rows: rows as u64,
             operators: operators_per_column,
-        }
+        })
     }
 
     #[inline]
@@ -123,6 +185,34 @@ impl CephalopodMathWorksheet {
     }
 }
 
+/// Every row, including the operator row, must be as wide as the first one and end in a newline.
+fn check_rows(input: &[u8], width: usize) -> Result<(), ParseError> {
+    for line in input.split_inclusive(|&c| c == b'\n') {
+        let row = line.strip_suffix(b"\n");
+
+        match row {
+            Some(row) if row.len() == width => {}
+            _ if line.len() == width => {
+                return Err(ParseError::eof(
+                    Puzzle::DAY,
+                    input,
+                    "expected a newline after the last row",
+                ));
+            }
+            _ => {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    input,
+                    row.unwrap_or(line),
+                    "row is not as wide as the first row",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::read_to_string;
@@ -138,7 +228,7 @@ mod tests {
     #[test]
     fn get_columns() {
         let input = read_to_string("example.txt").unwrap();
-        let worksheet = super::CephalopodMathWorksheet::from_slice(input.as_bytes());
+        let worksheet = super::CephalopodMathWorksheet::from_slice(input.as_bytes()).unwrap();
 
         assert_eq!(worksheet.get_column(0), &[123, 45, 6]);
         assert_eq!(worksheet.get_column(1), &[328, 64, 98]);
@@ -149,7 +239,7 @@ mod tests {
     #[test]
     fn get_column_operator() {
         let input = read_to_string("example.txt").unwrap();
-        let worksheet = super::CephalopodMathWorksheet::from_slice(input.as_bytes());
+        let worksheet = super::CephalopodMathWorksheet::from_slice(input.as_bytes()).unwrap();
 
         use super::Operator::*;
 
@@ -158,4 +248,38 @@ mod tests {
         assert!(matches!(worksheet.get_column_operator(2), Multiply));
         assert!(matches!(worksheet.get_column_operator(3), Add));
     }
+
+    #[test]
+    fn try_solve_rejects_unknown_operator() {
+        let error = super::try_solve(b"1 2\n3 4\n+ /\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (6, 3, 3));
+        assert_eq!(error.bytes, b"/");
+    }
+
+    #[test]
+    fn try_solve_rejects_invalid_number() {
+        let error = super::try_solve(b"1 2\n3 x\n+ *\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.bytes, b"x");
+    }
+
+    #[test]
+    fn try_solve_rejects_truncated_worksheet() {
+        let error = super::try_solve(b"1 2\n3 4\n+").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.bytes, b"+");
+
+        let error = super::try_solve(b"1 2\n3 4\n+ *").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 4));
+        assert!(error.bytes.is_empty());
+    }
+
+    #[test]
+    fn try_solve_rejects_extra_numbers_and_operators() {
+        let error = super::try_solve(b"1 2  \n3 4 5\n+ *  \n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = super::try_solve(b"1 2  \n3 4  \n+ * +\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 5));
+    }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{ParseError, Solution};
+
 mod bytes {
     pub const NEWLINE: u8 = b'\n';
     pub const SPACE: u8 = b' ';
@@ -6,9 +8,30 @@ mod bytes {
     pub const ZERO: u8 = b'0';
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+
+    type Input<'a> = CephalopodMathWorksheet<'a>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        CephalopodMathWorksheet::from_slice(input.as_bytes())
+    }
+
+    fn solve(worksheet: Self::Input<'_>) -> Self::Output {
+        worksheet.sum()
+    }
+}
+
 pub fn solve(input: &[u8]) -> u64 {
-    let worksheet = CephalopodMathWorksheet::from_slice(input);
-    worksheet.sum()
+    try_solve(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_solve(input: &[u8]) -> Result<u64, ParseError> {
+    Puzzle::try_run(aoc_core::from_utf8(Puzzle::DAY, input)?)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -52,7 +75,7 @@ impl Operator {
 }
 
 #[derive(Debug)]
-struct CephalopodMathWorksheet<'a> {
+pub struct CephalopodMathWorksheet<'a> {
     data: &'a [u8],
     height_in_bytes: usize,
     stride_in_bytes: usize,
@@ -60,20 +83,53 @@ struct CephalopodMathWorksheet<'a> {
 }
 
 impl<'a> CephalopodMathWorksheet<'a> {
-    fn from_slice(input: &'a [u8]) -> Self {
-        let width_in_bytes = input.iter().position(|&c| c == bytes::NEWLINE).unwrap();
+    fn from_slice(input: &'a [u8]) -> Result<Self, ParseError> {
+        let width_in_bytes = input
+            .iter()
+            .position(|&c| c == bytes::NEWLINE)
+            .ok_or_else(|| ParseError::eof(Puzzle::DAY, input, "expected a row of numbers"))?;
         let stride_in_bytes = width_in_bytes + 1;
+        check_rows(input, width_in_bytes)?;
+
         let height_in_bytes = input.len() / stride_in_bytes;
-        let last_line = &input[input.len() - stride_in_bytes..];
-        let operator_columns_count = unsafe { std::str::from_utf8_unchecked(last_line) }
-            .split_ascii_whitespace()
+        if height_in_bytes < 2 {
+            return Err(ParseError::eof(
+                Puzzle::DAY,
+                input,
+                "expected a row of operators",
+            ));
+        }
+
+        let (number_rows, last_line) = input.split_at(input.len() - stride_in_bytes);
+        if let Some(i) = number_rows
+            .iter()
+            .position(|&b| !matches!(b, b'0'..=b'9' | bytes::SPACE | bytes::NEWLINE))
+        {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &input[i..=i],
+                "expected a digit or a space",
+            ));
+        }
+        if let Some(i) = last_line
+            .iter()
+            .position(|&b| !matches!(b, bytes::PLUS | bytes::STAR | bytes::SPACE | bytes::NEWLINE))
+        {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &last_line[i..=i],
+                "expected `+` or `*`",
+            ));
+        }
+
+        let operator_columns_count = last_line
+            .iter()
+            .filter(|&&b| b == bytes::PLUS || b == bytes::STAR)
             .count();
 
-        let mut operators: Vec<Operator> = unsafe {
-            let mut vec = Vec::with_capacity(operator_columns_count);
-            vec.set_len(operator_columns_count);
-            vec
-        };
+        let mut operators = vec![Operator::new(OpKind::Add, 0, 0); operator_columns_count];
 
         let mut operator_index = 0;
         let mut cumulative_offset = 1;
@@ -95,12 +151,12 @@ impl<'a> CephalopodMathWorksheet<'a> {
             column_width = 0;
         }
 
-        Self {
+        Ok(Self {
             data: input,
             height_in_bytes,
             stride_in_bytes,
             operators,
-        }
+        })
     }
 
     #[inline]
@@ -140,6 +196,34 @@ impl<'a> CephalopodMathWorksheet<'a> {
     }
 }
 
+/// Every row, including the operator row, must be as wide as the first one and end in a newline.
+fn check_rows(input: &[u8], width: usize) -> Result<(), ParseError> {
+    for line in input.split_inclusive(|&c| c == b'\n') {
+        let row = line.strip_suffix(b"\n");
+
+        match row {
+            Some(row) if row.len() == width => {}
+            _ if line.len() == width => {
+                return Err(ParseError::eof(
+                    Puzzle::DAY,
+                    input,
+                    "expected a newline after the last row",
+                ));
+            }
+            _ => {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    input,
+                    row.unwrap_or(line),
+                    "row is not as wide as the first row",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::read_to_string;
@@ -205,7 +289,7 @@ mod tests {
         #[test]
         fn parses_single_add_column() {
             let input = b"1\n2\n3\n+\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
 
             assert_eq!(worksheet.operators.len(), 1);
             assert!(matches!(worksheet.operators[0].kind, OpKind::Add));
@@ -214,7 +298,7 @@ mod tests {
         #[test]
         fn parses_single_multiply_column() {
             let input = b"1\n2\n3\n*\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
 
             assert_eq!(worksheet.operators.len(), 1);
             assert!(matches!(worksheet.operators[0].kind, OpKind::Multiply));
@@ -223,7 +307,7 @@ mod tests {
         #[test]
         fn parses_multiple_operators() {
             let input = b"1 2\n3 4\n+ *\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
 
             assert_eq!(worksheet.operators.len(), 2);
             assert!(matches!(worksheet.operators[0].kind, OpKind::Add));
@@ -233,7 +317,7 @@ mod tests {
         #[test]
         fn calculates_correct_dimensions() {
             let input = b"123\n456\n789\n+  \n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
 
             assert_eq!(worksheet.stride_in_bytes, 4);
             assert_eq!(worksheet.height_in_bytes, 4);
@@ -246,60 +330,90 @@ mod tests {
         #[test]
         fn single_digit_add_column() {
             let input = b"1\n2\n3\n+\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 123);
         }
 
         #[test]
         fn single_digit_multiply_column() {
             let input = b"1\n2\n3\n*\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 123);
         }
 
         #[test]
         fn two_digit_add_column() {
             let input = b"12\n34\n56\n+ \n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 381);
         }
 
         #[test]
         fn two_digit_multiply_column() {
             let input = b"12\n34\n56\n* \n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 33210);
         }
 
         #[test]
         fn handles_spaces_in_numbers() {
             let input = b" 1\n 2\n 3\n +\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 123);
         }
 
         #[test]
         fn multiple_columns_mixed_operators() {
             let input = b"1 2\n3 4\n+ *\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 37);
         }
 
         #[test]
         fn all_add_operators() {
             let input = b"1 2 3\n4 5 6\n+ + +\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 75);
         }
 
         #[test]
         fn all_multiply_operators() {
             let input = b"1 2 3\n4 5 6\n* * *\n";
-            let worksheet = CephalopodMathWorksheet::from_slice(input);
+            let worksheet = CephalopodMathWorksheet::from_slice(input).unwrap();
             assert_eq!(worksheet.sum(), 75);
         }
     }
 
+    mod parse_error_tests {
+        #[test]
+        fn rejects_unknown_operator() {
+            let error = super::super::try_solve(b"1 2\n3 4\n+ -\n").unwrap_err();
+            assert_eq!((error.day, error.line, error.column), (6, 3, 3));
+            assert_eq!(error.bytes, b"-");
+        }
+
+        #[test]
+        fn rejects_invalid_digit() {
+            let error = super::super::try_solve(b"1 2\n3 x\n+ *\n").unwrap_err();
+            assert_eq!((error.line, error.column), (2, 3));
+            assert_eq!(error.bytes, b"x");
+        }
+
+        #[test]
+        fn rejects_jagged_rows() {
+            let error = super::super::try_solve(b"1 2\n3\n+ *\n").unwrap_err();
+            assert_eq!((error.line, error.column), (2, 1));
+            assert_eq!(error.bytes, b"3");
+        }
+
+        #[test]
+        fn rejects_missing_operator_row() {
+            let error = super::super::try_solve(b"1 2\n").unwrap_err();
+            assert_eq!((error.line, error.column), (2, 1));
+            assert!(error.bytes.is_empty());
+        }
+    }
+
     mod integration_tests {
         use super::*;
 
@@ -1,10 +1,10 @@
 use day_06_part_02::solve;
 
 fn main() {
-    let input = std::fs::read_to_string("puzzles/day-06-part-02/input.txt").unwrap();
+    let input = aoc_core::input!(std::env::args_os().nth(1)).unwrap_or_else(|e| panic!("{e}"));
     let timer = std::time::Instant::now();
     let result = solve(input.as_bytes());
     let elapsed = timer.elapsed();
     println!("Elapsed time: {:?}", elapsed);
-    assert_eq!(result, 11052310600986);
+    println!("Result: {result}");
 }
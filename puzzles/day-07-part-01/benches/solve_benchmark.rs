@@ -1,14 +1,30 @@
-use std::fs::read_to_string;
-
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day07::Manifold};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_07_part_01::solve;
+use day_07_part_01::Puzzle;
+
+aoc_core::count_allocations!();
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
-    let answer = solve(&input);
-    assert_eq!(answer, 1619);
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-07-part-01", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(c, "day-07-part-01", &[35, 71, 141, 283, 567], |side| {
+        Manifold {
+            width: side,
+            height: side,
+            ..Default::default()
+        }
+        .generate(BENCH_SEED)
+    });
 }
 
 criterion_group!(benches, bench_solve);
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
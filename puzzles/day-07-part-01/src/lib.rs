@@ -1,7 +1,29 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const PART: u8 = 1;
+
+    type Input<'a> = TachyonManifold<'a>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        TachyonManifold::from_slice(input.as_bytes())
+    }
+
+    fn solve(manifold: Self::Input<'_>) -> Self::Output {
+        manifold.trace_beam()
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let manifold = TachyonManifold::from_slice(input.as_bytes());
+    Puzzle::run(input)
+}
 
-    manifold.trace_beam()
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -33,7 +55,7 @@ impl Position {
     }
 }
 
-struct TachyonManifold<'a> {
+pub struct TachyonManifold<'a> {
     data: &'a [u8],
     width: u32,
     stride: u32,
@@ -41,20 +63,23 @@ struct TachyonManifold<'a> {
 }
 
 impl<'a> TachyonManifold<'a> {
-    fn from_slice(input: &'a [u8]) -> Self {
+    fn from_slice(input: &'a [u8]) -> Result<Self, ParseError> {
         let width = input
             .iter()
             .position(|&c| c == b'\n')
-            .unwrap_or(input.len()) as u32;
+            .unwrap_or(input.len());
+        check_grid(input, width)?;
+
+        let width = width as u32;
         let stride = width + 1_u32;
         let height = (input.len() + 1) as u32 / stride;
 
-        Self {
+        Ok(Self {
             data: input,
             width,
             stride,
             height,
-        }
+        })
     }
 
     fn starting_position(&self) -> Position {
@@ -114,6 +139,43 @@ impl<'a> TachyonManifold<'a> {
     }
 }
 
+/// The grid must be rectangular, contain only `.`, `^` and `S`, and have its
+/// beam source in the middle of the first row.
+fn check_grid(input: &[u8], width: usize) -> Result<(), ParseError> {
+    for line in input.split(|&c| c == b'\n') {
+        if line.is_empty() && line.as_ptr_range().end == input.as_ptr_range().end {
+            break;
+        }
+        if line.len() != width {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                line,
+                "row is not as wide as the first row",
+            ));
+        }
+        if let Some(x) = line.iter().position(|&b| !matches!(b, b'.' | b'^' | b'S')) {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &line[x..=x],
+                "expected `.`, `^` or `S`",
+            ));
+        }
+    }
+
+    if input.get(width / 2) != Some(&b'S') {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            input,
+            &input[..width],
+            "expected the beam source `S` in the middle of the first row",
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -159,7 +221,7 @@ mod tests {
         #[test]
         fn from_slice() {
             let input = read_to_string("example.txt").unwrap();
-            let manifold = crate::TachyonManifold::from_slice(input.as_bytes());
+            let manifold = crate::TachyonManifold::from_slice(input.as_bytes()).unwrap();
 
             assert_eq!(manifold.width, 15);
             assert_eq!(manifold.height, 16);
@@ -173,7 +235,7 @@ mod tests {
         #[case(14, 15, b'.')]
         fn get(#[case] x: u32, #[case] y: u32, #[case] expected: u8) {
             let input = read_to_string("example.txt").unwrap();
-            let manifold = crate::TachyonManifold::from_slice(input.as_bytes());
+            let manifold = crate::TachyonManifold::from_slice(input.as_bytes()).unwrap();
             let position = crate::Position { x, y };
             let byte = manifold.get(&position);
 
@@ -187,7 +249,7 @@ mod tests {
         #[case(14, 15, 254)]
         fn idx(#[case] x: u32, #[case] y: u32, #[case] expected: usize) {
             let input = read_to_string("example.txt").unwrap();
-            let manifold = crate::TachyonManifold::from_slice(input.as_bytes());
+            let manifold = crate::TachyonManifold::from_slice(input.as_bytes()).unwrap();
             let position = crate::Position { x, y };
             let index = manifold.idx(&position);
 
@@ -201,4 +263,18 @@ mod tests {
             assert!(!crate::TachyonManifold::should_split(b'S'));
         }
     }
+
+    #[test]
+    fn try_solve_rejects_malformed_grid() {
+        let error = super::try_solve("..S..\n.....\n..^.\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (7, 3, 1));
+        assert_eq!(error.bytes, b"..^.");
+
+        let error = super::try_solve("..S..\n..v..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.bytes, b"v");
+
+        let error = super::try_solve(".S...\n.....\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
 }
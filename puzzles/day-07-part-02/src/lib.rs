@@ -1,10 +1,63 @@
+use aoc_core::{ParseError, Solution};
 use wide::{CmpEq, u8x32};
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+
+    type Input<'a> = TachyonManifold<'a>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        TachyonManifold::from_slice(input.as_bytes())
+    }
+
+    fn solve(manifold: Self::Input<'_>) -> Self::Output {
+        count_timelines(&manifold)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let data = input.as_bytes();
-    let width = data.iter().position(|&c| c == b'\n').unwrap_or(data.len());
-    let stride = width + 1;
-    let height = (data.len() + 1) / stride;
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+pub struct TachyonManifold<'a> {
+    data: &'a [u8],
+    width: usize,
+    stride: usize,
+    height: usize,
+}
+
+impl<'a> TachyonManifold<'a> {
+    fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
+        let width = data.iter().position(|&c| c == b'\n').unwrap_or(data.len());
+        check_grid(data, width)?;
+
+        let stride = width + 1;
+        let height = (data.len() + 1) / stride;
+
+        Ok(Self {
+            data,
+            width,
+            stride,
+            height,
+        })
+    }
+}
+
+fn count_timelines(manifold: &TachyonManifold) -> usize {
+    let &TachyonManifold {
+        data,
+        width,
+        stride,
+        height,
+    } = manifold;
 
     // beams[i+1] corresponds to column i (padding for bounds)
     let mut beams = vec![0u64; width + 2];
@@ -15,10 +68,9 @@ pub fn solve(input: &str) -> usize {
     let beams_ptr = beams.as_mut_ptr();
 
     // Process rows with step 2, starting from row 2
-    let mut row_ptr = unsafe { data.as_ptr().add(2 * stride) };
-    let row_step = 2 * stride;
+    for y in (2..height).step_by(2) {
+        let row_ptr = unsafe { data.as_ptr().add(y * stride) };
 
-    for _ in (2..height).step_by(2) {
         // Process full row with SIMD - width is 141, so 4 chunks of 32 + 13 remainder
         let mut x = 0;
 
@@ -68,13 +120,12 @@ pub fn solve(input: &str) -> usize {
             x += 32;
         }
 
-        // Handle remainder with SIMD (mask out invalid bits)
+        // Handle remainder with SIMD, copied into a zeroed chunk so the load
+        // never reads past the end of the last row
         if x < width {
-            let chunk = u8x32::new(unsafe { *(row_ptr.add(x) as *const [u8; 32]) });
-            let mut mask = chunk.simd_eq(needle).to_bitmask();
-            // Mask out bits beyond width
-            let valid_bits = width - x;
-            mask &= (1u32 << valid_bits) - 1;
+            let mut tail = [0u8; 32];
+            tail[..width - x].copy_from_slice(&data[y * stride + x..y * stride + width]);
+            let mut mask = u8x32::new(tail).simd_eq(needle).to_bitmask();
 
             while mask != 0 {
                 let bit_pos = mask.trailing_zeros() as usize;
@@ -90,19 +141,107 @@ pub fn solve(input: &str) -> usize {
                 mask &= mask - 1;
             }
         }
-
-        row_ptr = unsafe { row_ptr.add(row_step) };
     }
 
     beams.iter().sum::<u64>() as usize
 }
 
+/// The grid must be rectangular, contain only `.`, `^` and `S`, and have its
+/// beam source in the middle of the first row.
+fn check_grid(input: &[u8], width: usize) -> Result<(), ParseError> {
+    for line in input.split(|&c| c == b'\n') {
+        if line.is_empty() && line.as_ptr_range().end == input.as_ptr_range().end {
+            break;
+        }
+        if line.len() != width {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                line,
+                "row is not as wide as the first row",
+            ));
+        }
+        if let Some(x) = line.iter().position(|&b| !matches!(b, b'.' | b'^' | b'S')) {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &line[x..=x],
+                "expected `.`, `^` or `S`",
+            ));
+        }
+    }
+
+    if input.get(width / 2) != Some(&b'S') {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            input,
+            &input[..width],
+            "expected the beam source `S` in the middle of the first row",
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    /// Reference without SIMD or pointer arithmetic that visits every row.
+    fn count_timelines_scalar(manifold: &TachyonManifold) -> usize {
+        let mut beams = vec![0u64; manifold.width + 2];
+        beams[manifold.width / 2 + 1] = 1;
+
+        for row in manifold.data.chunks(manifold.stride).skip(1) {
+            for x in (0..manifold.width).filter(|&x| row[x] == b'^') {
+                let count = beams[x + 1];
+                beams[x] += count;
+                beams[x + 1] = 0;
+                beams[x + 2] += count;
+            }
+        }
+
+        beams.iter().sum::<u64>() as usize
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn simd_agrees_with_scalar(mut rows in aoc_core::differential::grid(2..=16, b".^")) {
+            // zelfde vorm als de echte input: bron in het midden, splitters alleen op even rijen
+            let width = rows[0].len();
+            for row in rows.iter_mut().skip(1).step_by(2) {
+                row.fill(b'.');
+            }
+            rows[0].fill(b'.');
+            rows[0][width / 2] = b'S';
+
+            let input = aoc_core::differential::to_input(&rows);
+            // zonder afsluitende newline eindigt de laatste rij precies op het einde van de buffer
+            let input = input.strip_suffix('\n').unwrap();
+            let manifold = TachyonManifold::from_slice(input.as_bytes()).unwrap();
+
+            proptest::prop_assert_eq!(count_timelines(&manifold), count_timelines_scalar(&manifold));
+        }
+    }
+
     #[test]
     fn solve_example() {
         let input = std::fs::read_to_string("example.txt").unwrap();
         let result = super::solve(&input);
         assert_eq!(result, 40);
     }
+
+    #[test]
+    fn try_solve_rejects_malformed_grid() {
+        let error = super::try_solve("..S..\n.....\n..^.\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (7, 3, 1));
+        assert_eq!(error.bytes, b"..^.");
+
+        let error = super::try_solve("..S..\n..v..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.bytes, b"v");
+
+        let error = super::try_solve(".S...\n.....\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
 }
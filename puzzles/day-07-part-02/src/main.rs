@@ -5,11 +5,11 @@ use mimalloc::MiMalloc;
 static ALLOC: MiMalloc = MiMalloc;
 
 fn main() {
-    let input = std::fs::read_to_string("puzzles/day-07-part-02/input.txt").unwrap();
+    let input = aoc_core::input!(std::env::args_os().nth(1)).unwrap_or_else(|e| panic!("{e}"));
     let timer = std::time::Instant::now();
     let result = solve(&input);
     let elapsed = timer.elapsed();
 
     println!("Elapsed time: {:?}", elapsed);
-    assert_eq!(result, 23607984027985);
+    println!("Result: {result}");
 }
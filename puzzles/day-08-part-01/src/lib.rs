@@ -1,14 +1,56 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    const PART: u8 = 1;
+    // the example connects the 10 closest pairs, the solution always 1000
+    const EXAMPLES: bool = false;
+
+    type Input<'a> = Playground;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let playground = Playground::parse(input)?;
+
+        if playground.len() < 2 {
+            return Err(ParseError::eof(
+                Self::DAY,
+                input.as_bytes(),
+                "expected at least two junction boxes",
+            ));
+        }
+
+        Ok(playground)
+    }
+
+    fn solve(playground: Self::Input<'_>) -> Self::Output {
+        connect_closest(&playground, 1000)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    solve_with_connections(input, 1000)
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
 pub fn solve_with_connections(input: &str, num_connections: usize) -> usize {
-    let playground = Playground::parse(input);
+    connect_closest(&Playground::parse(input).unwrap(), num_connections)
+}
+
+fn connect_closest(playground: &Playground, num_connections: usize) -> usize {
     let edges = playground.edges_by_distance();
 
     let mut circuits = CircuitTracker::new(playground.len());
-    for edge in edges.iter().take(num_connections) {
-        circuits.connect(edge.from, edge.to);
+    {
+        aoc_core::phase!("union_find");
+        for edge in edges.iter().take(num_connections) {
+            circuits.connect(edge.from, edge.to);
+        }
     }
 
     circuits.product_of_three_largest_circuits()
@@ -47,9 +89,9 @@ pub struct Playground {
 }
 
 impl Playground {
-    pub fn parse(input: &str) -> Self {
-        let lines: Vec<_> = input
-            .as_bytes()
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let bytes = input.as_bytes();
+        let lines: Vec<_> = bytes
             .split(|&b| b == b'\n')
             .filter(|l| !l.is_empty())
             .collect();
@@ -60,28 +102,49 @@ impl Playground {
         let mut z = Vec::with_capacity(n);
 
         for line in lines {
-            let (px, py, pz) = Self::parse_position(line);
+            let (px, py, pz) = Self::parse_position(bytes, line)?;
             x.push(px);
             y.push(py);
             z.push(pz);
         }
 
-        Self { x, y, z }
+        Ok(Self { x, y, z })
     }
 
     #[inline]
-    fn parse_position(line: &[u8]) -> (u32, u32, u32) {
+    fn parse_position(input: &[u8], line: &[u8]) -> Result<(u32, u32, u32), ParseError> {
+        let missing = || {
+            ParseError::at(
+                Puzzle::DAY,
+                input,
+                line,
+                "expected three coordinates like `1,2,3`",
+            )
+        };
+
         let mut parts = line.split(|&b| b == b',');
-        (
-            Self::parse_u32(parts.next().unwrap()),
-            Self::parse_u32(parts.next().unwrap()),
-            Self::parse_u32(parts.next().unwrap()),
-        )
+        let mut next = || {
+            parts
+                .next()
+                .ok_or_else(missing)
+                .and_then(|part| Self::parse_u32(input, part))
+        };
+        let position = (next()?, next()?, next()?);
+
+        if parts.next().is_some() {
+            return Err(missing());
+        }
+
+        Ok(position)
     }
 
     #[inline]
-    fn parse_u32(s: &[u8]) -> u32 {
-        s.iter().fold(0u32, |acc, &b| acc * 10 + (b - b'0') as u32)
+    fn parse_u32(input: &[u8], s: &[u8]) -> Result<u32, ParseError> {
+        if s.is_empty() || !s.iter().all(u8::is_ascii_digit) {
+            return Err(ParseError::at(Puzzle::DAY, input, s, "expected a number"));
+        }
+
+        Ok(s.iter().fold(0u32, |acc, &b| acc * 10 + (b - b'0') as u32))
     }
 
     #[inline(always)]
@@ -89,6 +152,11 @@ impl Playground {
         self.x.len()
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
     #[inline(always)]
     fn squared_distance(&self, i: usize, j: usize) -> u64 {
         let dx = self.x[i] as i64 - self.x[j] as i64;
@@ -100,6 +168,7 @@ impl Playground {
     // step 1
     pub fn edges_by_distance(&self) -> Vec<StringOfLights> {
         const MAX_EDGES_NEEDED: usize = 1000;
+        aoc_core::phase!("edges_by_distance");
 
         let n = self.len();
         let mut edges = Vec::with_capacity(n * (n - 1) / 2);
@@ -229,7 +298,7 @@ mod tests {
 
         #[test]
         fn parse_positions() {
-            let pg = Playground::parse("1,2,3\n4,5,6\n");
+            let pg = Playground::parse("1,2,3\n4,5,6\n").unwrap();
             assert_eq!(pg.len(), 2);
             assert_eq!((pg.x[0], pg.y[0], pg.z[0]), (1, 2, 3));
             assert_eq!((pg.x[1], pg.y[1], pg.z[1]), (4, 5, 6));
@@ -237,7 +306,7 @@ mod tests {
 
         #[test]
         fn edges_sorted_by_distance() {
-            let pg = Playground::parse("0,0,0\n1,0,0\n10,0,0\n");
+            let pg = Playground::parse("0,0,0\n1,0,0\n10,0,0\n").unwrap();
             let edges = pg.edges_by_distance();
 
             assert_eq!(edges.len(), 3);
@@ -287,4 +356,25 @@ mod tests {
             assert_eq!(solve_with_connections(&input, 10), 40);
         }
     }
+
+    mod parse_errors {
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("1,2,3\n4,5\n", 2, 1, b"4,5".as_slice())]
+        #[case("1,2,3\n4,5,6,7\n", 2, 1, b"4,5,6,7".as_slice())]
+        #[case("1,2,3\n4,-5,6\n", 2, 3, b"-5".as_slice())]
+        #[case("1,2,3\n4,,6\n", 2, 3, b"".as_slice())]
+        #[case("1,2,3\n", 2, 1, b"".as_slice())]
+        fn try_solve_reports_location(
+            #[case] input: &str,
+            #[case] line: usize,
+            #[case] column: usize,
+            #[case] bytes: &[u8],
+        ) {
+            let error = crate::try_solve(input).unwrap_err();
+            assert_eq!((error.day, error.line, error.column), (8, line, column));
+            assert_eq!(error.bytes, bytes);
+        }
+    }
 }
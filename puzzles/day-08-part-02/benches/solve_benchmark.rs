@@ -1,12 +1,30 @@
-use std::{fs::read_to_string, time::Duration};
+use std::time::Duration;
 
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day08::JunctionBoxes};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_08_part_02::solve;
+use day_08_part_02::Puzzle;
+
+aoc_core::count_allocations!();
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    assert_eq!(solve(&input), 107256172);
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-08-part-02", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(
+        c,
+        "day-08-part-02",
+        &[250, 500, 1_000, 2_000, 4_000],
+        |count| {
+            JunctionBoxes {
+                count,
+                ..Default::default()
+            }
+            .generate(BENCH_SEED)
+        },
+    );
 }
 
 criterion_group! {
@@ -16,4 +34,9 @@ criterion_group! {
         .measurement_time(Duration::from_secs(60));
     targets = bench_solve
 }
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
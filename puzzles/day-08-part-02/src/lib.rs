@@ -1,9 +1,46 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
+
+use aoc_core::{ParseError, Solution};
 use wide::i32x8;
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+
+    type Input<'a> = Playground;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let playground = Playground::parse(input)?;
+
+        if playground.len() < 2 {
+            return Err(ParseError::eof(
+                Self::DAY,
+                input.as_bytes(),
+                "expected at least two junction boxes",
+            ));
+        }
+
+        Ok(playground)
+    }
+
+    fn solve(playground: Self::Input<'_>) -> Self::Output {
+        connect_until_single_circuit(&playground)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let playground = Playground::parse(input);
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+fn connect_until_single_circuit(playground: &Playground) -> usize {
     let num_coords = playground.len();
 
     let mut heap = playground.edges_as_heap();
@@ -12,12 +49,15 @@ pub fn solve(input: &str) -> usize {
     let mut last_edge = None;
     let mut edges_added = 0;
 
-    while let Some(Reverse(edge)) = heap.pop() {
-        if circuits.connect(edge.from, edge.to) {
-            last_edge = Some(edge);
-            edges_added += 1;
-            if edges_added == num_coords - 1 {
-                break;
+    {
+        aoc_core::phase!("union_find");
+        while let Some(Reverse(edge)) = heap.pop() {
+            if circuits.connect(edge.from, edge.to) {
+                last_edge = Some(edge);
+                edges_added += 1;
+                if edges_added == num_coords - 1 {
+                    break;
+                }
             }
         }
     }
@@ -101,9 +141,9 @@ pub struct Playground {
 }
 
 impl Playground {
-    pub fn parse(input: &str) -> Self {
-        let lines: Vec<_> = input
-            .as_bytes()
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let bytes = input.as_bytes();
+        let lines: Vec<_> = bytes
             .split(|&b| b == b'\n')
             .filter(|l| !l.is_empty())
             .collect();
@@ -113,38 +153,50 @@ impl Playground {
         let mut y = Vec::with_capacity(n);
         let mut z = Vec::with_capacity(n);
 
-        unsafe {
-            x.set_len(n);
-            y.set_len(n);
-            z.set_len(n);
-        }
-
-        for (idx, line) in lines.into_iter().enumerate() {
-            let (px, py, pz) = Self::parse_position(line);
-            unsafe {
-                debug_assert!(idx < n);
-                *x.get_unchecked_mut(idx) = px;
-                *y.get_unchecked_mut(idx) = py;
-                *z.get_unchecked_mut(idx) = pz;
-            }
+        for line in lines {
+            let (px, py, pz) = Self::parse_position(bytes, line)?;
+            x.push(px);
+            y.push(py);
+            z.push(pz);
         }
 
-        Self { x, y, z }
+        Ok(Self { x, y, z })
     }
 
     #[inline]
-    fn parse_position(line: &[u8]) -> (i32, i32, i32) {
+    fn parse_position(input: &[u8], line: &[u8]) -> Result<(i32, i32, i32), ParseError> {
+        let missing = || {
+            ParseError::at(
+                Puzzle::DAY,
+                input,
+                line,
+                "expected three coordinates like `1,2,3`",
+            )
+        };
+
         let mut parts = line.split(|&b| b == b',');
-        (
-            Self::parse_i32(parts.next().unwrap()),
-            Self::parse_i32(parts.next().unwrap()),
-            Self::parse_i32(parts.next().unwrap()),
-        )
+        let mut next = || {
+            parts
+                .next()
+                .ok_or_else(missing)
+                .and_then(|part| Self::parse_i32(input, part))
+        };
+        let position = (next()?, next()?, next()?);
+
+        if parts.next().is_some() {
+            return Err(missing());
+        }
+
+        Ok(position)
     }
 
     #[inline]
-    fn parse_i32(s: &[u8]) -> i32 {
-        s.iter().fold(0i32, |acc, &b| acc * 10 + (b - b'0') as i32)
+    fn parse_i32(input: &[u8], s: &[u8]) -> Result<i32, ParseError> {
+        if s.is_empty() || !s.iter().all(u8::is_ascii_digit) {
+            return Err(ParseError::at(Puzzle::DAY, input, s, "expected a number"));
+        }
+
+        Ok(s.iter().fold(0i32, |acc, &b| acc * 10 + (b - b'0') as i32))
     }
 
     #[inline(always)]
@@ -152,6 +204,11 @@ impl Playground {
         self.x.len()
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
     /// Get x-coordinate for a junction box
     #[inline(always)]
     pub fn x_coord(&self, jb: JunctionBox) -> i32 {
@@ -161,6 +218,7 @@ impl Playground {
     /// Build min-heap of all edges using cache-blocked iteration and i32x8 SIMD
     pub fn edges_as_heap(&self) -> BinaryHeap<Reverse<StringOfLights>> {
         const BLOCK_SIZE: usize = 64;
+        aoc_core::phase!("edges_as_heap");
 
         let num_coords = self.len();
         let num_edges = num_coords * (num_coords - 1) / 2;
@@ -395,7 +453,7 @@ mod tests {
 
         #[test]
         fn parse_positions() {
-            let pg = Playground::parse("1,2,3\n4,5,6\n");
+            let pg = Playground::parse("1,2,3\n4,5,6\n").unwrap();
             assert_eq!(pg.len(), 2);
             assert_eq!((pg.x[0], pg.y[0], pg.z[0]), (1, 2, 3));
             assert_eq!((pg.x[1], pg.y[1], pg.z[1]), (4, 5, 6));
@@ -403,22 +461,22 @@ mod tests {
 
         #[test]
         fn parse_handles_trailing_newline() {
-            let with_trailing = Playground::parse("1,2,3\n");
-            let without_trailing = Playground::parse("1,2,3");
+            let with_trailing = Playground::parse("1,2,3\n").unwrap();
+            let without_trailing = Playground::parse("1,2,3").unwrap();
             assert_eq!(with_trailing.len(), 1);
             assert_eq!(without_trailing.len(), 1);
         }
 
         #[test]
         fn x_coord_returns_correct_value() {
-            let pg = Playground::parse("10,20,30\n40,50,60\n");
+            let pg = Playground::parse("10,20,30\n40,50,60\n").unwrap();
             assert_eq!(pg.x_coord(JunctionBox::new(0)), 10);
             assert_eq!(pg.x_coord(JunctionBox::new(1)), 40);
         }
 
         #[test]
         fn edges_as_heap_creates_correct_number_of_edges() {
-            let pg = Playground::parse("0,0,0\n1,0,0\n0,1,0\n");
+            let pg = Playground::parse("0,0,0\n1,0,0\n0,1,0\n").unwrap();
             let heap = pg.edges_as_heap();
 
             assert_eq!(heap.len(), 3);
@@ -426,7 +484,7 @@ mod tests {
 
         #[test]
         fn edges_as_heap_calculates_correct_distances() {
-            let pg = Playground::parse("0,0,0\n3,4,0\n0,0,5\n");
+            let pg = Playground::parse("0,0,0\n3,4,0\n0,0,5\n").unwrap();
             let heap = pg.edges_as_heap();
 
             let mut distances: Vec<u64> = heap.into_iter().map(|r| r.0.squared_distance).collect();
@@ -511,4 +569,25 @@ mod tests {
             assert_eq!(solve(input), 20 * 100);
         }
     }
+
+    mod parse_errors {
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("1,2,3\n4,5\n", 2, 1, b"4,5".as_slice())]
+        #[case("1,2,3\n4,5,6,7\n", 2, 1, b"4,5,6,7".as_slice())]
+        #[case("1,2,3\n4,-5,6\n", 2, 3, b"-5".as_slice())]
+        #[case("1,2,3\n4,,6\n", 2, 3, b"".as_slice())]
+        #[case("1,2,3\n", 2, 1, b"".as_slice())]
+        fn try_solve_reports_location(
+            #[case] input: &str,
+            #[case] line: usize,
+            #[case] column: usize,
+            #[case] bytes: &[u8],
+        ) {
+            let error = crate::try_solve(input).unwrap_err();
+            assert_eq!((error.day, error.line, error.column), (8, line, column));
+            assert_eq!(error.bytes, bytes);
+        }
+    }
 }
@@ -1,26 +1,68 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+
+    type Input<'a> = Vec<Vec2u32>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve(points: Self::Input<'_>) -> Self::Output {
+        max_surface_area(&points) as usize
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let points = parse_input(input);
-    max_surface_area(&points) as usize
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
-fn parse_u32vec2(s: &[u8]) -> Vec2u32 {
+fn parse_u32vec2(s: &[u8]) -> Result<Vec2u32, ParseError> {
+    let missing = || ParseError::at(Puzzle::DAY, s, s, "expected a position like `7,1`");
     let mut parts = s.split(|&b| b == b',');
 
-    let x_bytes = parts.next().unwrap();
-    let x = parse_u32(x_bytes);
+    let x_bytes = parts.next().ok_or_else(missing)?;
+    let x = parse_coordinate(s, x_bytes)?;
 
-    let y_bytes = parts.next().unwrap();
-    let y = parse_u32(y_bytes);
+    let y_bytes = parts.next().ok_or_else(missing)?;
+    let y = parse_coordinate(s, y_bytes)?;
 
-    Vec2u32::new(x, y)
+    if parts.next().is_some() {
+        return Err(missing());
+    }
+
+    Ok(Vec2u32::new(x, y))
+}
+
+fn parse_coordinate(line: &[u8], bytes: &[u8]) -> Result<u32, ParseError> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            bytes,
+            "expected a number",
+        ));
+    }
+
+    Ok(parse_u32(bytes))
 }
 
-fn parse_input(input: &str) -> Vec<Vec2u32> {
-    input
-        .as_bytes()
+fn parse_input(input: &str) -> Result<Vec<Vec2u32>, ParseError> {
+    let bytes = input.as_bytes();
+
+    bytes
         .split(|&c| c == b'\n')
         .filter(|line| !line.is_empty())
-        .map(|line| parse_u32vec2(line))
+        .map(|line| parse_u32vec2(line).map_err(|e| e.within(bytes, line)))
         .collect()
 }
 
@@ -56,7 +98,7 @@ fn max_surface_area(points: &[Vec2u32]) -> u64 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Vec2u32 {
+pub struct Vec2u32 {
     x: u32,
     y: u32,
 }
@@ -104,7 +146,7 @@ mod tests {
     #[test]
     fn parse_input_works() {
         let input = EXAMPLE_INPUT;
-        let result = parse_input(input);
+        let result = parse_input(input).unwrap();
 
         assert_eq!(
             result,
@@ -124,7 +166,7 @@ mod tests {
     #[test]
     fn parse_u32vec2_works() {
         let input = b"12,34";
-        let result = parse_u32vec2(input);
+        let result = parse_u32vec2(input).unwrap();
 
         assert_eq!(result, Vec2u32::new(12, 34));
     }
@@ -138,4 +180,20 @@ mod tests {
         let result = a.surface_between(&b);
         assert_eq!(result, surface);
     }
+
+    #[rstest]
+    #[case("7,1\n11,1\n11\n", 3, 1, b"11".as_slice())]
+    #[case("7,1\n11,1,2\n", 2, 1, b"11,1,2".as_slice())]
+    #[case("7,1\n11,x\n", 2, 4, b"x".as_slice())]
+    #[case("7,1\n,1\n", 2, 1, b"".as_slice())]
+    fn try_solve_reports_malformed_position(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (9, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
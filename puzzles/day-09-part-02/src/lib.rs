@@ -1,6 +1,33 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+
+    type Input<'a> = Vec<Point>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve(points: Self::Input<'_>) -> Self::Output {
+        largest_valid_rectangle(&points)
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let points = parse_input(input);
-    let compressor = CoordCompressor::new(&points);
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+fn largest_valid_rectangle(points: &[Point]) -> usize {
+    let compressor = CoordCompressor::new(points);
 
     // compress + pad to avoid bounds checks in hotpath
     let compressed_points: Vec<Point> = points
@@ -95,6 +122,7 @@ impl Grid {
     // flood fill up to shape
     #[inline]
     pub fn with_outside_filled(mut self) -> Self {
+        aoc_core::phase!("with_outside_filled");
         self.df_flood_fill(Point::new(0, 0), Cell::Outside);
         self
     }
@@ -113,6 +141,7 @@ impl Grid {
     // convert to prefix sum
     #[inline]
     pub fn into_prefix_sum(self) -> PrefixSum2D {
+        aoc_core::phase!("into_prefix_sum");
         PrefixSum2D::new(&self.data, self.cols, self.rows)
     }
 
@@ -173,24 +202,43 @@ impl Grid {
     }
 }
 
-fn parse_point(s: &[u8]) -> Point {
+fn parse_point(s: &[u8]) -> Result<Point, ParseError> {
+    let missing = || ParseError::at(Puzzle::DAY, s, s, "expected a position like `7,1`");
     let mut parts = s.split(|&b| b == b',');
 
-    let x_bytes = parts.next().unwrap();
-    let x = parse_u32(x_bytes);
+    let x_bytes = parts.next().ok_or_else(missing)?;
+    let x = parse_coordinate(s, x_bytes)?;
+
+    let y_bytes = parts.next().ok_or_else(missing)?;
+    let y = parse_coordinate(s, y_bytes)?;
 
-    let y_bytes = parts.next().unwrap();
-    let y = parse_u32(y_bytes);
+    if parts.next().is_some() {
+        return Err(missing());
+    }
 
-    Point::new(x, y)
+    Ok(Point::new(x, y))
 }
 
-fn parse_input(input: &str) -> Vec<Point> {
-    input
-        .as_bytes()
+fn parse_coordinate(line: &[u8], bytes: &[u8]) -> Result<u32, ParseError> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            bytes,
+            "expected a number",
+        ));
+    }
+
+    Ok(parse_u32(bytes))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    let bytes = input.as_bytes();
+
+    bytes
         .split(|&c| c == b'\n')
         .filter(|line| !line.is_empty())
-        .map(|line| parse_point(line))
+        .map(|line| parse_point(line).map_err(|e| e.within(bytes, line)))
         .collect()
 }
 
@@ -259,7 +307,7 @@ impl PrefixSum2D {
 
 /// A 2D point with u32 coordinates
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
+pub struct Point {
     x: u32,
     y: u32,
 }
@@ -374,7 +422,7 @@ mod tests {
 
     #[test]
     fn parse_input_works() {
-        let result = parse_input(EXAMPLE_INPUT);
+        let result = parse_input(EXAMPLE_INPUT).unwrap();
 
         assert_eq!(
             result,
@@ -393,7 +441,7 @@ mod tests {
 
     #[test]
     fn parse_point_works() {
-        assert_eq!(parse_point(b"12,34"), Point::new(12, 34));
+        assert_eq!(parse_point(b"12,34").unwrap(), Point::new(12, 34));
     }
 
     #[test]
@@ -558,4 +606,20 @@ mod tests {
             assert_eq!(compressor.rows(), expected_rows);
         }
     }
+
+    #[rstest::rstest]
+    #[case("7,1\n11,1\n11\n", 3, 1, b"11".as_slice())]
+    #[case("7,1\n11,1,2\n", 2, 1, b"11,1,2".as_slice())]
+    #[case("7,1\n11,x\n", 2, 4, b"x".as_slice())]
+    #[case("7,1\n,1\n", 2, 1, b"".as_slice())]
+    fn try_solve_reports_malformed_position(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (9, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
@@ -1,10 +1,29 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+
+    type Input<'a> = Factory;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input.as_bytes())
+    }
+
+    fn solve(factory: Self::Input<'_>) -> Self::Output {
+        factory.machines.iter().map(solve_machine).sum()
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    let factory = parse_input(input.as_bytes());
-    factory
-        .machines
-        .iter()
-        .map(|machine| solve_machine(machine))
-        .sum()
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
 }
 
 fn solve_machine(machine: &Machine) -> usize {
@@ -49,6 +68,7 @@ impl LinearSystem {
     }
 
     fn reduce_to_echelon_form(&mut self) {
+        aoc_core::phase!("reduce_to_echelon_form");
         let mut current_row = 0;
 
         for col in 0..self.num_variables {
@@ -209,66 +229,106 @@ impl BitSet {
     }
 }
 
-fn parse_input(input: &[u8]) -> Factory {
+fn parse_input(input: &[u8]) -> Result<Factory, ParseError> {
     let machines = input
         .split(|&c| c == b'\n')
         .filter(|line| !line.is_empty())
-        .map(|line| parse_machine(line))
-        .collect();
+        .map(|line| parse_machine(line).map_err(|e| e.within(input, line)))
+        .collect::<Result<_, _>>()?;
 
-    Factory { machines }
+    Ok(Factory { machines })
 }
 
-fn parse_machine(line: &[u8]) -> Machine {
-    let (mask, mut current_index) = parse_diagram(&line, 1);
+fn parse_machine(line: &[u8]) -> Result<Machine, ParseError> {
+    if line.first() != Some(&b'[') {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            &line[..1],
+            "expected an indicator light diagram like `[.##.]`",
+        ));
+    }
+
+    let (mask, mut current_index) = parse_diagram(line, 1)?;
     let mut buttons = vec![];
 
     loop {
-        let byte = line[current_index];
-        
+        let Some(&byte) = line.get(current_index) else {
+            return Err(ParseError::eof(
+                Puzzle::DAY,
+                line,
+                "expected joltage requirements like `{3,5,4,7}`",
+            ));
+        };
+
         if byte == b'(' {
-            let (button_mask, new_index) = parse_button(&line, current_index + 1);
+            let (button_mask, new_index) = parse_button(line, current_index + 1)?;
             buttons.push(button_mask);
             current_index = new_index;
         }
 
         if byte == b'{' {
-            return Machine {
+            return Ok(Machine {
                 diagram_mask: mask,
                 button_masks: buttons,
-            }
+            });
         }
 
         current_index += 1;
     }
 }
 
-fn parse_button(input: &[u8], index: usize) -> (u16, usize) {
+fn parse_button(input: &[u8], index: usize) -> Result<(u16, usize), ParseError> {
     let mut mask = 0u16;
 
-    for i in index..input.len() {
-        let b = input[i];
+    for (i, &b) in input.iter().enumerate().skip(index) {
         if b == b')' {
-            return (mask, i + 1);
+            return Ok((mask, i + 1));
         }
 
         if b == b',' {
             continue;
         }
 
+        if !b.is_ascii_digit() {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &input[i..=i],
+                "expected a light index",
+            ));
+        }
+
         mask |= 1 << (b - b'0');
     }
-    
-    panic!("Unterminated button");
+
+    Err(ParseError::eof(Puzzle::DAY, input, "unterminated button, expected `)`"))
 }
 
-fn parse_diagram(input: &[u8], index: usize) -> (u16, usize) {
+fn parse_diagram(input: &[u8], index: usize) -> Result<(u16, usize), ParseError> {
     let mut mask = 0u16;
 
-    for i in index..input.len() {
-        let b = input[i];
+    for (i, &b) in input.iter().enumerate().skip(index) {
         if b == b']' {
-            return (mask, i + 1);
+            return Ok((mask, i + 1));
+        }
+
+        if b != b'.' && b != b'#' {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &input[i..=i],
+                "expected `.` or `#`",
+            ));
+        }
+
+        if i - index >= u16::BITS as usize {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                input,
+                &input[index..=i],
+                "diagram has more than 16 lights",
+            ));
         }
 
         if b == b'.' {
@@ -277,8 +337,8 @@ fn parse_diagram(input: &[u8], index: usize) -> (u16, usize) {
 
         mask |= 1 << (i - index);
     }
-    
-    panic!("Unterminated diagram");
+
+    Err(ParseError::eof(Puzzle::DAY, input, "unterminated diagram, expected `]`"))
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -288,7 +348,7 @@ struct Machine {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
-struct Factory {
+pub struct Factory {
     machines: Vec<Machine>,
 }
 
@@ -319,13 +379,13 @@ mod tests {
     #[case(EXAMPLE_LINE_2, 3)]
     #[case(EXAMPLE_LINE_3, 2)]
     fn test_solve_machine(#[case] line: &str, #[case] expected: usize) {
-        let machine = crate::parse_machine(line.as_bytes());
+        let machine = crate::parse_machine(line.as_bytes()).unwrap();
         assert_eq!(crate::solve_machine(&machine), expected);
     }
 
     #[test]
     fn test_parse_input() {
-        let factory = crate::parse_input(EXAMPLE_INPUT.as_bytes());
+        let factory = crate::parse_input(EXAMPLE_INPUT.as_bytes()).unwrap();
         assert_eq!(factory.machines.len(), 3);
     }
 
@@ -370,7 +430,7 @@ mod tests {
         }
     )]
     fn parse_machine(#[case] line: &str, #[case] expected: Machine) {
-        let parsed = crate::parse_machine(line.as_bytes());
+        let parsed = crate::parse_machine(line.as_bytes()).unwrap();
         assert_eq!(parsed, expected);
     }
 
@@ -379,7 +439,7 @@ mod tests {
     #[case(b"[...#.]", 1 << 3)]
     #[case(b"[.###.#]", 1 << 1 | 1 << 2 | 1 << 3 | 1 << 5)]
     fn parse_diagram(#[case] input: &[u8], #[case] expected_mask: u16) {
-        let (parsed_mask, idx) = crate::parse_diagram(&input, 1);
+        let (parsed_mask, idx) = crate::parse_diagram(input, 1).unwrap();
         assert_eq!(parsed_mask, expected_mask);
         assert_eq!(idx, input.len());
     }
@@ -389,8 +449,27 @@ mod tests {
     #[case(b"(1,3)", 1 << 1 | 1 << 3)]
     #[case(b"(0,1,2,3,4,5)", 1 << 0 | 1 << 1 | 1 << 2 | 1 << 3 | 1 << 4 | 1 << 5)]
     fn parse_button(#[case] input: &[u8], #[case] expected_mask: u16) {
-        let (parsed_mask, idx) = crate::parse_button(&input, 1);
+        let (parsed_mask, idx) = crate::parse_button(input, 1).unwrap();
         assert_eq!(parsed_mask, expected_mask);
         assert_eq!(idx, input.len());
     }
+
+    #[rstest]
+    #[case("[.##.] (3) {3}\n(3) {3}\n", 2, 1, b"(".as_slice())]
+    #[case("[.##.] (3) {3}\n[.#x.] (3) {3}\n", 2, 4, b"x".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##. (3) {3}\n", 2, 6, b" ".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3,a) {3}\n", 2, 11, b"a".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3,1\n", 2, 12, b"".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3)\n", 2, 11, b"".as_slice())]
+    #[case("[.................] (3) {3}\n", 1, 2, b".................".as_slice())]
+    fn try_solve_reports_malformed_machine(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = crate::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (10, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
@@ -1,12 +1,26 @@
-use std::{fs::read_to_string, time::Duration};
+use std::time::Duration;
 
+aoc_core::count_allocations!();
+
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day10::Machines};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_10_part_02::solve;
+use day_10_part_02::Puzzle;
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    assert_eq!(solve(&input), 17424);
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-10-part-02", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(c, "day-10-part-02", &[4, 8, 12, 16], |buttons| {
+        Machines {
+            lights: 10,
+            buttons,
+            ..Default::default()
+        }
+        .generate(BENCH_SEED)
+    });
 }
 
 criterion_group! {
@@ -16,4 +30,9 @@ criterion_group! {
         .measurement_time(Duration::from_secs(60));
     targets = bench_solve
 }
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
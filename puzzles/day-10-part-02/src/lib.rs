@@ -1,11 +1,32 @@
-use good_lp::{constraint, variable, Expression, ProblemVariables, Solution, SolverModel};
+use aoc_core::{ParseError, Solution as _};
+use good_lp::{Expression, ProblemVariables, Solution, SolverModel, constraint, variable};
 use rayon::prelude::*;
 
+pub struct Puzzle;
+
+impl aoc_core::Solution for Puzzle {
+    const DAY: u8 = 10;
+    const PART: u8 = 2;
+    const PARALLEL: bool = true;
+
+    type Input<'a> = Vec<Machine>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input.as_bytes())
+    }
+
+    fn solve(machines: Self::Input<'_>) -> Self::Output {
+        machines.par_iter().map(solve_machine).sum()
+    }
+}
+
 pub fn solve(input: &str) -> usize {
-    parse_input(input.as_bytes())
-        .par_iter()
-        .map(|machine| solve_machine(machine))
-        .sum()
+    <Puzzle as aoc_core::Solution>::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    <Puzzle as aoc_core::Solution>::try_run(input)
 }
 
 fn solve_machine(machine: &Machine) -> usize {
@@ -45,28 +66,37 @@ fn solve_machine(machine: &Machine) -> usize {
     }
 }
 
-struct Machine {
+pub struct Machine {
     buttons: Vec<Vec<usize>>,
     targets: Vec<i64>,
 }
 
-fn parse_input(input: &[u8]) -> Vec<Machine> {
+fn parse_input(input: &[u8]) -> Result<Vec<Machine>, ParseError> {
     input
         .split(|&c| c == b'\n')
         .filter(|line| !line.is_empty())
-        .map(parse_machine)
+        .map(|line| parse_machine(line).map_err(|e| e.within(input, line)))
         .collect()
 }
 
-fn parse_machine(line: &[u8]) -> Machine {
+fn parse_machine(line: &[u8]) -> Result<Machine, ParseError> {
     let mut buttons = Vec::new();
     let mut targets = Vec::new();
-    let mut i = 0;
 
-    // Skip diagram [...]
-    while line[i] != b']' {
-        i += 1;
+    if line.first() != Some(&b'[') {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            &line[..1],
+            "expected an indicator light diagram like `[.##.]`",
+        ));
     }
+
+    // Skip diagram [...]
+    let mut i = line
+        .iter()
+        .position(|&c| c == b']')
+        .ok_or_else(|| ParseError::eof(Puzzle::DAY, line, "unterminated diagram, expected `]`"))?;
     i += 1;
 
     while i < line.len() {
@@ -74,9 +104,26 @@ fn parse_machine(line: &[u8]) -> Machine {
             b'(' => {
                 i += 1;
                 let mut counters = Vec::new();
-                while line[i] != b')' {
-                    if line[i].is_ascii_digit() {
-                        counters.push((line[i] - b'0') as usize);
+                loop {
+                    match line.get(i) {
+                        Some(b')') => break,
+                        Some(b',') => {}
+                        Some(c) if c.is_ascii_digit() => counters.push((c - b'0') as usize),
+                        Some(_) => {
+                            return Err(ParseError::at(
+                                Puzzle::DAY,
+                                line,
+                                &line[i..=i],
+                                "expected a counter index",
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::eof(
+                                Puzzle::DAY,
+                                line,
+                                "unterminated button, expected `)`",
+                            ));
+                        }
                     }
                     i += 1;
                 }
@@ -87,27 +134,50 @@ fn parse_machine(line: &[u8]) -> Machine {
                 i += 1;
                 let mut num = 0i64;
                 let mut in_num = false;
-                while line[i] != b'}' {
-                    if line[i].is_ascii_digit() {
-                        num = num * 10 + (line[i] - b'0') as i64;
-                        in_num = true;
-                    } else if line[i] == b',' && in_num {
-                        targets.push(num);
-                        num = 0;
-                        in_num = false;
+                loop {
+                    match line.get(i) {
+                        Some(b'}') => break,
+                        Some(&c) if c.is_ascii_digit() => {
+                            num = num * 10 + (c - b'0') as i64;
+                            in_num = true;
+                        }
+                        Some(b',') if in_num => {
+                            targets.push(num);
+                            num = 0;
+                            in_num = false;
+                        }
+                        Some(_) => {
+                            return Err(ParseError::at(
+                                Puzzle::DAY,
+                                line,
+                                &line[i..=i],
+                                "expected a joltage requirement",
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::eof(
+                                Puzzle::DAY,
+                                line,
+                                "unterminated joltage requirements, expected `}`",
+                            ));
+                        }
                     }
                     i += 1;
                 }
                 if in_num {
                     targets.push(num);
                 }
-                break;
+                return Ok(Machine { buttons, targets });
             }
             _ => i += 1,
         }
     }
 
-    Machine { buttons, targets }
+    Err(ParseError::eof(
+        Puzzle::DAY,
+        line,
+        "expected joltage requirements like `{3,5,4,7}`",
+    ))
 }
 
 #[cfg(test)]
@@ -117,7 +187,8 @@ mod tests {
 
     const EXAMPLE_LINE_1: &str = r#"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"#;
     const EXAMPLE_LINE_2: &str = r#"[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}"#;
-    const EXAMPLE_LINE_3: &str = r#"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"#;
+    const EXAMPLE_LINE_3: &str =
+        r#"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"#;
 
     const EXAMPLE_INPUT: &str = indoc! {r#"
         [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
@@ -135,13 +206,32 @@ mod tests {
     #[case(EXAMPLE_LINE_2, 12)]
     #[case(EXAMPLE_LINE_3, 11)]
     fn test_solve_machine(#[case] line: &str, #[case] expected: usize) {
-        let machine = crate::parse_machine(line.as_bytes());
+        let machine = crate::parse_machine(line.as_bytes()).unwrap();
         assert_eq!(crate::solve_machine(&machine), expected);
     }
 
     #[test]
     fn test_parse_input() {
-        let machines = crate::parse_input(EXAMPLE_INPUT.as_bytes());
+        let machines = crate::parse_input(EXAMPLE_INPUT.as_bytes()).unwrap();
         assert_eq!(machines.len(), 3);
     }
+
+    #[rstest]
+    #[case("[.##.] (3) {3}\n(3) {3}\n", 2, 1, b"(".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##. (3) {3}\n", 2, 14, b"".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3,a) {3}\n", 2, 11, b"a".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3,1\n", 2, 12, b"".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3) {3,x}\n", 2, 15, b"x".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3) {3,4\n", 2, 16, b"".as_slice())]
+    #[case("[.##.] (3) {3}\n[.##.] (3)\n", 2, 11, b"".as_slice())]
+    fn try_solve_reports_malformed_machine(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = crate::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (10, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
 }
@@ -1,12 +1,25 @@
-use std::{fs::read_to_string, time::Duration};
+use std::time::Duration;
 
+aoc_core::count_allocations!();
+
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day11::Devices};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_11_part_01::solve;
+use day_11_part_01::Puzzle;
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    assert_eq!(solve(&input), 472);
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-11-part-01", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(c, "day-11-part-01", &[100, 1_000, 5_000, 15_000], |count| {
+        Devices {
+            count,
+            ..Default::default()
+        }
+        .generate(BENCH_SEED)
+    });
 }
 
 criterion_group! {
@@ -16,4 +29,9 @@ criterion_group! {
         .measurement_time(Duration::from_secs(60));
     targets = bench_solve
 }
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
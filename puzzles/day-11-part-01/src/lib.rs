@@ -1,20 +1,47 @@
-
 #![allow(dead_code)]
 
-use node_index::NodeIndex;
+use aoc_core::{ParseError, Solution};
 use edge_index::EdgeIndex;
 use node_hash::NodeHash;
+use node_index::NodeIndex;
+
+// NodeHash telt per letter één op, dus "zzz" komt op 26³ + 2 uit
+const MAX_HASHES: usize = 26 * 26 * 26 + 2;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+
+    type Input<'a> = Graph;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Graph::from_input(input.as_bytes())
+    }
+
+    fn solve(graph: Self::Input<'_>) -> Self::Output {
+        count_paths_from_you_to_out(&graph)
+    }
+}
 
 pub fn solve(input: &str) -> usize {
-    let graph = Graph::from_input(input.as_bytes());
+    Puzzle::run(input)
+}
 
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+fn count_paths_from_you_to_out(graph: &Graph) -> usize {
     let start = graph.get_by_hash(NodeHash::from_slice(b"you"));
     let end = graph.get_by_hash(NodeHash::from_slice(b"out"));
 
     graph.find_paths(start, end)
 }
 
-struct Graph {
+pub struct Graph {
     nodes: Nodes,
     edges: Edges,
     hash_to_index: NodeIndexLookupTable,
@@ -41,7 +68,7 @@ impl Edges {
 }
 
 impl Graph {
-    fn from_input(input: &[u8]) -> Self {
+    fn from_input(input: &[u8]) -> Result<Self, ParseError> {
         // Count lines and edges in one pass
         let (num_lines, num_edges) = input.iter().fold((0, 0), |(lines, spaces), &b| {
             (lines + (b == b'\n') as usize, spaces + (b == b' ') as usize)
@@ -53,7 +80,9 @@ impl Graph {
 
         // 1st pass register all node names (just the first 3 chars per line)
         let mut node_idx = 0u16;
-        for line in input.split(|&c| c == b'\n').filter(|l| l.len() >= 3) {
+        for line in input.split(|&c| c == b'\n').filter(|l| !l.is_empty()) {
+            check_line(line).map_err(|e| e.within(input, line))?;
+
             let hash = NodeHash::from_slice(&line[0..3]);
             hash_to_index.insert(hash, NodeIndex::new(node_idx));
             node_idx += 1;
@@ -65,7 +94,7 @@ impl Graph {
         hash_to_index.insert(out_hash, out_index);
 
         // 2nd pass parse edges and build nodes
-        for line in input.split(|&c| c == b'\n').filter(|l| l.len() >= 3) {
+        for line in input.split(|&c| c == b'\n').filter(|l| !l.is_empty()) {
             let hash = NodeHash::from_slice(&line[0..3]);
             let start_edge = EdgeIndex::new(edges.len());
 
@@ -91,11 +120,11 @@ impl Graph {
             end_edge: EdgeIndex::new(edges.len()),
         });
 
-        Graph {
+        Ok(Graph {
             nodes: Nodes { nodes },
             edges: Edges { edges },
             hash_to_index: hash_to_index.build(),
-        }
+        })
     }
 
     fn find_paths(&self, start: &Node, end: &Node) -> usize {
@@ -117,6 +146,50 @@ impl Graph {
     }
 }
 
+/// A line looks like `aaa: bbb ccc`; device names are three lowercase letters.
+fn check_line(line: &[u8]) -> Result<(), ParseError> {
+    let (name, rest) = line.split_at(line.len().min(3));
+    check_name(line, name)?;
+
+    let Some(mut outputs) = rest.strip_prefix(b":") else {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            &rest[..rest.len().min(1)],
+            "expected `:` after the device name",
+        ));
+    };
+
+    while !outputs.is_empty() {
+        let Some(tail) = outputs.strip_prefix(b" ") else {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                line,
+                &outputs[..1],
+                "expected a space between device names",
+            ));
+        };
+        let (name, tail) = tail.split_at(tail.len().min(3));
+        check_name(line, name)?;
+        outputs = tail;
+    }
+
+    Ok(())
+}
+
+fn check_name(line: &[u8], name: &[u8]) -> Result<(), ParseError> {
+    if name.len() == 3 && name.iter().all(u8::is_ascii_lowercase) {
+        return Ok(());
+    }
+
+    Err(ParseError::at(
+        Puzzle::DAY,
+        line,
+        name,
+        "expected a three letter device name",
+    ))
+}
+
 mod node_index {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[repr(transparent)]
@@ -177,8 +250,16 @@ mod node_hash {
         }
 
         pub fn from_slice(s: &[u8]) -> Self {
-            debug_assert!(s.len() == 3, "Invalid node name length: {}", std::str::from_utf8(s).unwrap());
-            debug_assert!(s.iter().all(|&b| b.is_ascii_lowercase()), "Invalid node name: {}", std::str::from_utf8(s).unwrap());
+            debug_assert!(
+                s.len() == 3,
+                "Invalid node name length: {}",
+                std::str::from_utf8(s).unwrap()
+            );
+            debug_assert!(
+                s.iter().all(|&b| b.is_ascii_lowercase()),
+                "Invalid node name: {}",
+                std::str::from_utf8(s).unwrap()
+            );
 
             let left_most = Self::get_base26_offset(s[0], 0);
             let middle = Self::get_base26_offset(s[1], 1);
@@ -189,7 +270,7 @@ mod node_hash {
 }
 
 struct NodeIndexLookupTable {
-    table: [NodeIndex; 26 * 26 * 26],
+    table: [NodeIndex; MAX_HASHES],
 }
 
 impl NodeIndexLookupTable {
@@ -200,13 +281,13 @@ impl NodeIndexLookupTable {
 }
 
 struct NodeIndexLookupTableBuilder {
-    table: [NodeIndex; 26 * 26 * 26],
+    table: [NodeIndex; MAX_HASHES],
 }
 
 impl NodeIndexLookupTableBuilder {
     fn new() -> Self {
         NodeIndexLookupTableBuilder {
-            table: [NodeIndex::new(0); 26 * 26 * 26],
+            table: [NodeIndex::new(0); MAX_HASHES],
         }
     }
 
@@ -228,7 +309,7 @@ impl NodeIndexLookupTableBuilder {
 struct Node {
     hash: NodeHash,
     start_edge: EdgeIndex,
-    end_edge: EdgeIndex
+    end_edge: EdgeIndex,
 }
 
 impl Node {
@@ -266,6 +347,28 @@ mod tests {
         assert_eq!(result, 5);
     }
 
+    #[test]
+    fn solve_accepts_the_last_device_name() {
+        assert_eq!(super::solve("you: zzz\nzzz: out\n"), 1);
+    }
+
+    #[rstest]
+    #[case("aaa: bbb\nbb: out\n", 2, 1, b"bb:".as_slice())]
+    #[case("aaa: bbb\nbbb out\n", 2, 4, b" ".as_slice())]
+    #[case("aaa: bbb\nbbb: ouT\n", 2, 6, b"ouT".as_slice())]
+    #[case("aaa: bbb\nbbb:out\n", 2, 5, b"o".as_slice())]
+    #[case("aaa: bbb\nbbb: ou\n", 2, 6, b"ou".as_slice())]
+    fn try_solve_reports_malformed_line(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (11, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
+
     mod node_index {
         use super::*;
 
@@ -369,8 +472,14 @@ mod tests {
                     Node::new(NodeHash::from_slice(b"def")),
                 ],
             };
-            assert_eq!(nodes.get(NodeIndex::new(0)).hash, NodeHash::from_slice(b"abc"));
-            assert_eq!(nodes.get(NodeIndex::new(1)).hash, NodeHash::from_slice(b"def"));
+            assert_eq!(
+                nodes.get(NodeIndex::new(0)).hash,
+                NodeHash::from_slice(b"abc")
+            );
+            assert_eq!(
+                nodes.get(NodeIndex::new(1)).hash,
+                NodeHash::from_slice(b"def")
+            );
         }
     }
 
@@ -411,7 +520,7 @@ mod tests {
         #[test]
         fn find_paths_single_step() {
             let input = b"abc: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let start = graph.get_by_hash(NodeHash::from_slice(b"abc"));
             let end = graph.get_by_hash(NodeHash::from_slice(b"out"));
             let path_count = graph.find_paths(start, end);
@@ -421,7 +530,7 @@ mod tests {
         #[test]
         fn find_paths_two_steps() {
             let input = b"abc: def\ndef: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let start = graph.get_by_hash(NodeHash::from_slice(b"abc"));
             let end = graph.get_by_hash(NodeHash::from_slice(b"out"));
             let path_count = graph.find_paths(start, end);
@@ -430,8 +539,8 @@ mod tests {
 
         #[test]
         fn find_paths_multiple_paths() {
-            let input = b"abc: def ghi\n def: out\nghi: out\n";
-            let graph = Graph::from_input(input);
+            let input = b"abc: def ghi\ndef: out\nghi: out\n";
+            let graph = Graph::from_input(input).unwrap();
             let start = graph.get_by_hash(NodeHash::from_slice(b"abc"));
             let end = graph.get_by_hash(NodeHash::from_slice(b"out"));
             let path_count = graph.find_paths(start, end);
@@ -441,7 +550,7 @@ mod tests {
         #[test]
         fn parses_node_count() {
             let input = b"abc: def ghi\njkl: mno out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             // 2 lines + out node
             assert_eq!(graph.nodes.nodes.len(), 3);
         }
@@ -449,7 +558,7 @@ mod tests {
         #[test]
         fn parses_edge_count() {
             let input = b"abc: def ghi\njkl: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             // abc->def, abc->ghi, jkl->out
             assert_eq!(graph.edges.edges.len(), 3);
         }
@@ -457,7 +566,7 @@ mod tests {
         #[test]
         fn out_node_has_no_edges() {
             let input = b"abc: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let out_idx = graph.hash_to_index.get(NodeHash::from_slice(b"out"));
             let out_node = graph.nodes.get(out_idx);
             assert!(graph.edges.get(out_node).is_empty());
@@ -466,18 +575,18 @@ mod tests {
         #[test]
         fn lookup_resolves_correctly() {
             let input = b"abc: def\ndef: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let abc_idx = graph.hash_to_index.get(NodeHash::from_slice(b"abc"));
             let def_idx = graph.hash_to_index.get(NodeHash::from_slice(b"def"));
             let abc_node = graph.nodes.get(abc_idx);
             let abc_edges = graph.edges.get(abc_node);
             assert_eq!(abc_edges[0], def_idx);
         }
-        
+
         #[test]
         fn out_node_index_is_last() {
             let input = b"abc: out\ndef: out\nghi: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let out_idx = graph.hash_to_index.get(NodeHash::from_slice(b"out"));
             // out should be at index 3 (after abc=0, def=1, ghi=2)
             assert_eq!(out_idx.into_inner(), 3);
@@ -486,7 +595,7 @@ mod tests {
         #[test]
         fn out_node_hash_is_correct() {
             let input = b"abc: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let out_idx = graph.hash_to_index.get(NodeHash::from_slice(b"out"));
             let out_node = graph.nodes.get(out_idx);
             assert_eq!(out_node.hash, NodeHash::from_slice(b"out"));
@@ -495,11 +604,15 @@ mod tests {
         #[test]
         fn edges_point_to_out_node() {
             let input = b"abc: out\ndef: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let out_idx = graph.hash_to_index.get(NodeHash::from_slice(b"out"));
 
-            let abc_node = graph.nodes.get(graph.hash_to_index.get(NodeHash::from_slice(b"abc")));
-            let def_node = graph.nodes.get(graph.hash_to_index.get(NodeHash::from_slice(b"def")));
+            let abc_node = graph
+                .nodes
+                .get(graph.hash_to_index.get(NodeHash::from_slice(b"abc")));
+            let def_node = graph
+                .nodes
+                .get(graph.hash_to_index.get(NodeHash::from_slice(b"def")));
 
             assert_eq!(graph.edges.get(abc_node)[0], out_idx);
             assert_eq!(graph.edges.get(def_node)[0], out_idx);
@@ -508,9 +621,9 @@ mod tests {
         #[test]
         fn out_is_last_node_in_vec() {
             let input = b"aaa: out\nbbb: out\n";
-            let graph = Graph::from_input(input);
+            let graph = Graph::from_input(input).unwrap();
             let last_node = graph.nodes.nodes.last().unwrap();
             assert_eq!(last_node.hash, NodeHash::from_slice(b"out"));
         }
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,38 @@
+use aoc_core::{ParseError, Solution};
 use graph::Graph;
 use node_hash::NodeHash;
 
 const BASE: u16 = 26;
-const MAX_HASHES: usize = (BASE * BASE * BASE) as usize;
+// NodeHash telt per letter één op, dus "zzz" komt op 26³ + 2 uit
+const MAX_HASHES: usize = (BASE * BASE * BASE) as usize + 2;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+
+    type Input<'a> = Graph;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Graph::from_input(input.as_bytes())
+    }
+
+    fn solve(graph: Self::Input<'_>) -> Self::Output {
+        count_paths_through_dac_and_fft(&graph)
+    }
+}
 
 pub fn solve(input: &str) -> u64 {
-    let graph = Graph::from_input(input.as_bytes());
+    Puzzle::run(input)
+}
 
+pub fn try_solve(input: &str) -> Result<u64, ParseError> {
+    Puzzle::try_run(input)
+}
+
+fn count_paths_through_dac_and_fft(graph: &Graph) -> u64 {
     let svr = graph.get_index_by_hash(NodeHash::from_slice(b"svr"));
     let out = graph.get_index_by_hash(NodeHash::from_slice(b"out"));
     let dac = graph.get_index_by_hash(NodeHash::from_slice(b"dac"));
@@ -36,11 +62,13 @@ pub fn solve(input: &str) -> u64 {
 }
 
 mod graph {
-    use crate::MAX_HASHES;
+    use aoc_core::{ParseError, Solution};
+
+    use crate::{MAX_HASHES, Puzzle};
 
-    use super::node_index::NodeIndex;
     use super::edge_index::EdgeIndex;
     use super::node_hash::NodeHash;
+    use super::node_index::NodeIndex;
 
     pub struct Graph {
         nodes: Nodes,
@@ -87,7 +115,7 @@ mod graph {
             self.nodes.len()
         }
 
-        pub fn from_input(input: &[u8]) -> Self {
+        pub fn from_input(input: &[u8]) -> Result<Self, ParseError> {
             let (num_lines, num_edges) = input.iter().fold((0, 0), |(lines, spaces), &b| {
                 (lines + (b == b'\n') as usize, spaces + (b == b' ') as usize)
             });
@@ -98,7 +126,9 @@ mod graph {
 
             // 1st pass register nodes
             let mut node_idx = 0u16;
-            for line in input.split(|&c| c == b'\n').filter(|l| l.len() >= 3) {
+            for line in input.split(|&c| c == b'\n').filter(|l| !l.is_empty()) {
+                check_line(line).map_err(|e| e.within(input, line))?;
+
                 let hash = NodeHash::from_slice(&line[0..3]);
                 hash_to_index.insert(hash, NodeIndex::new(node_idx));
                 node_idx += 1;
@@ -110,7 +140,7 @@ mod graph {
             hash_to_index.insert(out_hash, out_index);
 
             // 2nd pass parse edges and build nodes
-            for line in input.split(|&c| c == b'\n').filter(|l| l.len() >= 3) {
+            for line in input.split(|&c| c == b'\n').filter(|l| !l.is_empty()) {
                 let hash = NodeHash::from_slice(&line[0..3]);
                 let start_edge = EdgeIndex::new(edges.len());
 
@@ -136,18 +166,23 @@ mod graph {
                 end_edge: EdgeIndex::new(edges.len()),
             });
 
-            Graph {
+            Ok(Graph {
                 nodes: Nodes { nodes },
                 edges: Edges { edges },
                 hash_to_index: hash_to_index.build(),
-            }
+            })
         }
 
         pub fn get_index_by_hash(&self, hash: NodeHash) -> NodeIndex {
             self.hash_to_index.get(hash)
         }
 
-        pub fn count_paths_memo(&self, current: NodeIndex, end: NodeIndex, memo: &mut [Option<u64>]) -> u64 {
+        pub fn count_paths_memo(
+            &self,
+            current: NodeIndex,
+            end: NodeIndex,
+            memo: &mut [Option<u64>],
+        ) -> u64 {
             if current == end {
                 return 1;
             }
@@ -207,7 +242,7 @@ mod graph {
     struct Node {
         hash: NodeHash,
         start_edge: EdgeIndex,
-        end_edge: EdgeIndex
+        end_edge: EdgeIndex,
     }
 
     impl Node {
@@ -221,6 +256,50 @@ mod graph {
         }
     }
 
+    /// A line looks like `aaa: bbb ccc`; device names are three lowercase letters.
+    fn check_line(line: &[u8]) -> Result<(), ParseError> {
+        let (name, rest) = line.split_at(line.len().min(3));
+        check_name(line, name)?;
+
+        let Some(mut outputs) = rest.strip_prefix(b":") else {
+            return Err(ParseError::at(
+                Puzzle::DAY,
+                line,
+                &rest[..rest.len().min(1)],
+                "expected `:` after the device name",
+            ));
+        };
+
+        while !outputs.is_empty() {
+            let Some(tail) = outputs.strip_prefix(b" ") else {
+                return Err(ParseError::at(
+                    Puzzle::DAY,
+                    line,
+                    &outputs[..1],
+                    "expected a space between device names",
+                ));
+            };
+            let (name, tail) = tail.split_at(tail.len().min(3));
+            check_name(line, name)?;
+            outputs = tail;
+        }
+
+        Ok(())
+    }
+
+    fn check_name(line: &[u8], name: &[u8]) -> Result<(), ParseError> {
+        if name.len() == 3 && name.iter().all(u8::is_ascii_lowercase) {
+            return Ok(());
+        }
+
+        Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            name,
+            "expected a three letter device name",
+        ))
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -307,8 +386,14 @@ mod graph {
                         Node::new(NodeHash::from_slice(b"def")),
                     ],
                 };
-                assert_eq!(nodes.get(NodeIndex::new(0)).hash, NodeHash::from_slice(b"abc"));
-                assert_eq!(nodes.get(NodeIndex::new(1)).hash, NodeHash::from_slice(b"def"));
+                assert_eq!(
+                    nodes.get(NodeIndex::new(0)).hash,
+                    NodeHash::from_slice(b"abc")
+                );
+                assert_eq!(
+                    nodes.get(NodeIndex::new(1)).hash,
+                    NodeHash::from_slice(b"def")
+                );
             }
         }
 
@@ -349,40 +434,34 @@ mod graph {
             #[test]
             fn find_paths_single_step() {
                 let input = b"abc: out\n";
-                let graph = Graph::from_input(input);
-                let path_count = graph.find_paths_by_hash(
-                    NodeHash::from_slice(b"abc"),
-                    NodeHash::from_slice(b"out")
-                );
+                let graph = Graph::from_input(input).unwrap();
+                let path_count = graph
+                    .find_paths_by_hash(NodeHash::from_slice(b"abc"), NodeHash::from_slice(b"out"));
                 assert_eq!(path_count, 1);
             }
 
             #[test]
             fn find_paths_two_steps() {
                 let input = b"abc: def\ndef: out\n";
-                let graph = Graph::from_input(input);
-                let path_count = graph.find_paths_by_hash(
-                    NodeHash::from_slice(b"abc"),
-                    NodeHash::from_slice(b"out")
-                );
+                let graph = Graph::from_input(input).unwrap();
+                let path_count = graph
+                    .find_paths_by_hash(NodeHash::from_slice(b"abc"), NodeHash::from_slice(b"out"));
                 assert_eq!(path_count, 1);
             }
 
             #[test]
             fn find_paths_multiple_paths() {
                 let input = b"abc: def ghi\ndef: out\nghi: out\n";
-                let graph = Graph::from_input(input);
-                let path_count = graph.find_paths_by_hash(
-                    NodeHash::from_slice(b"abc"),
-                    NodeHash::from_slice(b"out")
-                );
+                let graph = Graph::from_input(input).unwrap();
+                let path_count = graph
+                    .find_paths_by_hash(NodeHash::from_slice(b"abc"), NodeHash::from_slice(b"out"));
                 assert_eq!(path_count, 2);
             }
 
             #[test]
             fn parses_node_count() {
                 let input = b"abc: def ghi\njkl: mno out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 // 2 lines + out node
                 assert_eq!(graph.node_count(), 3);
             }
@@ -390,7 +469,7 @@ mod graph {
             #[test]
             fn parses_edge_count() {
                 let input = b"abc: def ghi\njkl: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 // abc->def, abc->ghi, jkl->out
                 assert_eq!(graph.edges.len(), 3);
             }
@@ -398,23 +477,25 @@ mod graph {
             #[test]
             fn out_node_has_no_edges() {
                 let input = b"abc: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 assert_eq!(graph.out_node_edges_count(NodeHash::from_slice(b"out")), 0);
             }
 
             #[test]
             fn lookup_resolves_correctly() {
                 let input = b"abc: def\ndef: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 let def_idx = graph.get_index_by_hash(NodeHash::from_slice(b"def"));
-                let abc_edges: Vec<_> = graph.get_edges_for_hash(NodeHash::from_slice(b"abc")).collect();
+                let abc_edges: Vec<_> = graph
+                    .get_edges_for_hash(NodeHash::from_slice(b"abc"))
+                    .collect();
                 assert_eq!(abc_edges[0], def_idx);
             }
 
             #[test]
             fn out_node_index_is_last() {
                 let input = b"abc: out\ndef: out\nghi: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 let out_idx = graph.get_index_by_hash(NodeHash::from_slice(b"out"));
                 // out should be at index 3 (after abc=0, def=1, ghi=2)
                 assert_eq!(out_idx.into_inner(), 3);
@@ -423,7 +504,7 @@ mod graph {
             #[test]
             fn out_node_hash_is_correct() {
                 let input = b"abc: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 let out_idx = graph.get_index_by_hash(NodeHash::from_slice(b"out"));
                 // Verify the out node is at the expected index
                 assert_eq!(out_idx.into_inner(), 1);
@@ -432,11 +513,15 @@ mod graph {
             #[test]
             fn edges_point_to_out_node() {
                 let input = b"abc: out\ndef: out\n";
-                let graph = Graph::from_input(input);
+                let graph = Graph::from_input(input).unwrap();
                 let out_idx = graph.get_index_by_hash(NodeHash::from_slice(b"out"));
 
-                let abc_edges: Vec<_> = graph.get_edges_for_hash(NodeHash::from_slice(b"abc")).collect();
-                let def_edges: Vec<_> = graph.get_edges_for_hash(NodeHash::from_slice(b"def")).collect();
+                let abc_edges: Vec<_> = graph
+                    .get_edges_for_hash(NodeHash::from_slice(b"abc"))
+                    .collect();
+                let def_edges: Vec<_> = graph
+                    .get_edges_for_hash(NodeHash::from_slice(b"def"))
+                    .collect();
 
                 assert_eq!(abc_edges[0], out_idx);
                 assert_eq!(def_edges[0], out_idx);
@@ -445,8 +530,11 @@ mod graph {
             #[test]
             fn out_is_last_node_in_vec() {
                 let input = b"aaa: out\nbbb: out\n";
-                let graph = Graph::from_input(input);
-                assert_eq!(graph.nodes.last().map(|n| n.hash), Some(NodeHash::from_slice(b"out")));
+                let graph = Graph::from_input(input).unwrap();
+                assert_eq!(
+                    graph.nodes.last().map(|n| n.hash),
+                    Some(NodeHash::from_slice(b"out"))
+                );
             }
         }
     }
@@ -489,7 +577,7 @@ mod edge_index {
 mod node_hash {
     use crate::BASE;
 
-    const POWERS_OF_BASE: [u16; 3] = [(BASE * BASE) as u16, BASE as u16, 1];
+    const POWERS_OF_BASE: [u16; 3] = [BASE * BASE, BASE, 1];
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[repr(transparent)]
@@ -514,8 +602,16 @@ mod node_hash {
         }
 
         pub fn from_slice(s: &[u8]) -> Self {
-            debug_assert!(s.len() == 3, "Invalid node name length: {}", std::str::from_utf8(s).unwrap());
-            debug_assert!(s.iter().all(|&b| b.is_ascii_lowercase()), "Invalid node name: {}", std::str::from_utf8(s).unwrap());
+            debug_assert!(
+                s.len() == 3,
+                "Invalid node name length: {}",
+                std::str::from_utf8(s).unwrap()
+            );
+            debug_assert!(
+                s.iter().all(|&b| b.is_ascii_lowercase()),
+                "Invalid node name: {}",
+                std::str::from_utf8(s).unwrap()
+            );
 
             let left_most = Self::get_base26_offset(s[0], 0);
             let middle = Self::get_base26_offset(s[1], 1);
@@ -528,8 +624,8 @@ mod node_hash {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::node_index::NodeIndex;
     use crate::edge_index::EdgeIndex;
+    use crate::node_index::NodeIndex;
     use rstest::rstest;
 
     const EXAMPLE: &str = indoc::indoc! {
@@ -555,6 +651,28 @@ mod tests {
         assert_eq!(result, 2);
     }
 
+    #[test]
+    fn solve_accepts_the_last_device_name() {
+        assert_eq!(super::solve("svr: fft\nfft: zzz\nzzz: dac\ndac: out\n"), 1);
+    }
+
+    #[rstest]
+    #[case("aaa: bbb\nbb: out\n", 2, 1, b"bb:".as_slice())]
+    #[case("aaa: bbb\nbbb out\n", 2, 4, b" ".as_slice())]
+    #[case("aaa: bbb\nbbb: ouT\n", 2, 6, b"ouT".as_slice())]
+    #[case("aaa: bbb\nbbb:out\n", 2, 5, b"o".as_slice())]
+    #[case("aaa: bbb\nbbb: ou\n", 2, 6, b"ou".as_slice())]
+    fn try_solve_reports_malformed_line(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let error = super::try_solve(input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (11, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
+
     mod node_index {
         use super::*;
 
@@ -1,13 +1,25 @@
-use std::{fs::read_to_string, time::Duration};
+use std::time::Duration;
 
+aoc_core::count_allocations!();
+
+use aoc_core::bench::{bench_scaling, bench_solution};
+use aoc_gen::{BENCH_SEED, day12::Regions};
 use criterion::{Criterion, criterion_group, criterion_main};
-use day_12_part_01::solve;
+use day_12_part_01::Puzzle;
 
 fn bench_solve(c: &mut Criterion) {
-    let input = read_to_string("input.txt").unwrap();
-    c.bench_function("solve", |b| b.iter(|| solve(std::hint::black_box(&input))));
-    let result = solve(&input);
-    assert_eq!(result, 579);
+    let input = aoc_core::input!().unwrap_or_else(|e| panic!("{e}"));
+    bench_solution::<Puzzle>(c, "day-12-part-01", &input);
+}
+
+fn bench_scale(c: &mut Criterion) {
+    bench_scaling::<Puzzle>(c, "day-12-part-01", &[1_000, 10_000, 100_000], |count| {
+        Regions {
+            count,
+            ..Default::default()
+        }
+        .generate(BENCH_SEED)
+    });
 }
 
 criterion_group! {
@@ -17,4 +29,9 @@ criterion_group! {
         .measurement_time(Duration::from_secs(60));
     targets = bench_solve
 }
-criterion_main!(benches);
+criterion_group! {
+    name = scaling;
+    config = Criterion::default().sample_size(20);
+    targets = bench_scale
+}
+criterion_main!(benches, scaling);
@@ -1,49 +1,119 @@
+use aoc_core::{ParseError, Solution, parse_number};
+
 const NUM_OF_CELLS: usize = 7;
 const START_OFFSET: usize = 95;
 const DIMS_OFFSET: usize = DIM_LEN + DIM_LEN + 2; // 12x34:
 const DIM_LEN: usize = 2;
 
-pub fn solve(input: &str) -> usize {
-    let b = input.as_bytes();
-    let mut i = START_OFFSET;
-    let mut count = 0;
-
-    while i < b.len() {
-        if i + DIMS_OFFSET <= b.len() && b[i + DIM_LEN] == b'x' {
-            let width = append(num(b[i]) as usize,  b[i + 1]);
-            let height = append(
-                num(b[i + 3]) as usize, b[i + 4]);
-            i += DIMS_OFFSET;
-
-            let mut total = 0;
-            while i < b.len() && b[i] != b'\n' {
-                if b[i] >= b'0' && b[i] <= b'9' {
-                    let mut n = 0;
-                    while i < b.len() && b[i] >= b'0' && b[i] <= b'9' {
-                        n = append(n, b[i]);
-                        i += 1;
-                    }
-                    total += n;
-                } else {
-                    i += 1;
-                }
-            }
-            count += (total * NUM_OF_CELLS <= width * height) as usize;
-        }
-        while i < b.len() && b[i] != b'\n' {
-            i += 1;
-        }
-        i += 1;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+    // the example is harder than the real input, which the solution relies on
+    const EXAMPLES: bool = false;
+
+    type Input<'a> = Vec<Region>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_regions(input.as_bytes())
+    }
+
+    fn solve(regions: Self::Input<'_>) -> Self::Output {
+        regions
+            .iter()
+            .filter(|region| region.presents * NUM_OF_CELLS <= region.width * region.height)
+            .count()
     }
-    count
 }
 
-#[inline(always)]
-const fn append(num: usize, byte: u8) -> usize {
-    num * 10 + (byte - b'0') as usize
+pub fn solve(input: &str) -> usize {
+    Puzzle::run(input)
+}
+
+pub fn try_solve(input: &str) -> Result<usize, ParseError> {
+    Puzzle::try_run(input)
+}
+
+pub struct Region {
+    width: usize,
+    height: usize,
+    presents: usize,
+}
+
+fn parse_regions(b: &[u8]) -> Result<Vec<Region>, ParseError> {
+    let Some(regions) = b.get(START_OFFSET..) else {
+        return Err(ParseError::eof(
+            Puzzle::DAY,
+            b,
+            "expected the present shapes before the regions",
+        ));
+    };
+
+    regions
+        .split(|&c| c == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_region(line).map_err(|e| e.within(b, line)))
+        .collect()
 }
 
-#[inline(always)]
-const fn num(byte: u8) -> u8 {
-    byte - b'0' 
+fn parse_region(line: &[u8]) -> Result<Region, ParseError> {
+    if line.len() < DIMS_OFFSET || line[DIM_LEN] != b'x' || line[DIMS_OFFSET - 1] != b':' {
+        return Err(ParseError::at(
+            Puzzle::DAY,
+            line,
+            &line[..line.len().min(DIMS_OFFSET)],
+            "expected a region like `12x34:`",
+        ));
+    }
+
+    let width = parse_number(Puzzle::DAY, line, &line[..DIM_LEN])?;
+    let height = parse_number(Puzzle::DAY, line, &line[DIM_LEN + 1..DIMS_OFFSET - 1])?;
+
+    let mut presents = 0;
+    for count in line[DIMS_OFFSET..].split(|&c| c == b' ').filter(|c| !c.is_empty()) {
+        presents += parse_number::<usize>(Puzzle::DAY, line, count)?;
+    }
+
+    Ok(Region {
+        width,
+        height,
+        presents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    const SHAPES: &str = "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##\n\n2:\n.##\n###\n##.\n\n3:\n##.\n###\n##.\n\n4:\n###\n#..\n###\n\n5:\n###\n.#.\n###\n\n";
+
+    #[test]
+    fn solve_counts_regions_that_fit() {
+        let input = format!("{SHAPES}12x05: 1 0 1 0 2 2\n04x04: 1 1 1 1 1 1\n");
+        assert_eq!(super::solve(&input), 1);
+    }
+
+    #[rstest]
+    #[case("12x05: 1 0 1 0 2 2\n4x4: 0 0 0 0 2 0\n", 32, 1, b"4x4: 0".as_slice())]
+    #[case("12x05: 1 0 1 0 2 2\n1ax05: 1 0 1 0 2 2\n", 32, 1, b"1a".as_slice())]
+    #[case("12x05: 1 0 1 0 2 2\n12x05: 1 0 x 0 2 2\n", 32, 12, b"x".as_slice())]
+    fn try_solve_reports_malformed_region(
+        #[case] regions: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] bytes: &[u8],
+    ) {
+        let input = format!("{SHAPES}{regions}");
+        let error = super::try_solve(&input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (12, line, column));
+        assert_eq!(error.bytes, bytes);
+    }
+
+    #[test]
+    fn try_solve_requires_the_shapes() {
+        let error = super::try_solve("0:\n###\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
 }
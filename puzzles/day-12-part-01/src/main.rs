@@ -1,9 +1,9 @@
 fn main() {
-    let input = std::fs::read_to_string("puzzles/day-12-part-01/input.txt").unwrap();
+    let input = aoc_core::input!(std::env::args_os().nth(1)).unwrap_or_else(|e| panic!("{e}"));
     let timer = std::time::Instant::now();
     let result = day_12_part_01::solve(&input);
     let elapsed = timer.elapsed();
 
     println!("Elapsed: {elapsed:?}");
-    assert_eq!(result, 579);
+    println!("Result: {result}");
 }